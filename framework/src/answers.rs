@@ -0,0 +1,130 @@
+use crate::{
+    error::{Context, Error},
+    result::{Result, ResultExt},
+};
+use std::{fmt::Write, path::PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Expected answers, stored as `answers/NN.txt`, with one `part: answer`
+/// line per part. Newlines in multi-line answers are escaped as `\n`.
+#[derive(Debug)]
+pub struct Answers {
+    dir: PathBuf,
+}
+
+impl Default for Answers {
+    fn default() -> Self {
+        Answers {
+            dir: PathBuf::from("./answers"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn new() -> Answers {
+        Default::default()
+    }
+
+    pub fn with_dir(dir: impl Into<PathBuf>) -> Answers {
+        Answers { dir: dir.into() }
+    }
+
+    fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("{day:0>2}.txt"))
+    }
+
+    /// Returns the known answers of a day as `(part, answer)` pairs.
+    pub fn get(&self, day: u32) -> Result<Vec<(String, String)>> {
+        let contents = match std::fs::read_to_string(self.path(day)) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(nr, line)| {
+                line.split_once(": ")
+                    .map(|(part, answer)| (part.to_owned(), unescape(answer)))
+                    .ok_or(Error::InvalidInput("malformed line in answers file".into()))
+                    .context(Context::line(nr + 1))
+            })
+            .collect()
+    }
+
+    /// Stores the given answers for a day, keeping previously recorded
+    /// answers of other parts.
+    pub fn record<'a>(
+        &self,
+        day: u32,
+        answers: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<()> {
+        let mut known = self.get(day)?;
+        for (part, answer) in answers {
+            match known.iter_mut().find(|(p, _)| p == part) {
+                Some((_, existing)) => answer.clone_into(existing),
+                None => known.push((part.to_owned(), answer.to_owned())),
+            }
+        }
+        known.sort();
+
+        let mut contents = String::new();
+        for (part, answer) in known {
+            _ = writeln!(contents, "{part}: {}", escape(&answer));
+        }
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(day), contents)?;
+        Ok(())
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => unescaped.push('\n'),
+            ('\\', Some('\\')) => unescaped.push('\\'),
+            _ => {
+                unescaped.push(c);
+                continue;
+            }
+        }
+        chars.next();
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        for answer in ["123", "#.#\n.#.", "a\\nb\\", "\\"] {
+            assert_eq!(answer, unescape(&escape(answer)));
+        }
+        assert_eq!("#.#\\n.#.", escape("#.#\n.#."));
+    }
+}
@@ -0,0 +1,135 @@
+//! Cooperative cancellation of long-running parts, such as after a timeout.
+//! The runner makes a token current while it executes a part, and code that
+//! calls [`check`], such as the search helpers in [`graph`](crate::graph),
+//! stops once that token is cancelled.
+
+use std::{
+    cell::RefCell,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// Number of [`Checkpoint::tick`] calls between actual checks.
+const CHECK_INTERVAL: u32 = 1024;
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    /// When the token cancels itself, and the timeout that it's based on.
+    deadline: Option<(Instant, Duration)>,
+}
+
+/// The payload that a cancelled part unwinds with, turned into an error by
+/// the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled {
+    pub timeout: Option<Duration>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        Default::default()
+    }
+
+    /// A token that cancels itself once `timeout` has passed.
+    pub fn with_timeout(timeout: Duration) -> CancellationToken {
+        CancellationToken {
+            cancelled: Default::default(),
+            deadline: Some((Instant::now() + timeout, timeout)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|(deadline, _)| Instant::now() >= deadline)
+    }
+
+    /// Runs `f` with this as the current token on this thread.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<CancellationToken>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.replace(Some(self.clone())));
+        f()
+    }
+}
+
+/// Stops the current part if its token was cancelled, by unwinding with
+/// [`Cancelled`]. Does nothing outside of the runner.
+pub fn check() {
+    let cancelled = CURRENT.with_borrow(|token| {
+        token
+            .as_ref()
+            .filter(|token| token.is_cancelled())
+            .map(|token| Cancelled {
+                timeout: token.deadline.map(|(_, timeout)| timeout),
+            })
+    });
+    if let Some(cancelled) = cancelled {
+        std::panic::resume_unwind(Box::new(cancelled));
+    }
+}
+
+/// Only calls [`check`] every so often, for use in hot loops.
+#[derive(Debug, Default)]
+pub struct Checkpoint {
+    count: u32,
+}
+
+impl Checkpoint {
+    pub fn new() -> Checkpoint {
+        Default::default()
+    }
+
+    #[inline]
+    pub fn tick(&mut self) {
+        self.count += 1;
+        if self.count == CHECK_INTERVAL {
+            self.count = 0;
+            check();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cancellation() {
+        let spin = || {
+            let mut checkpoint = Checkpoint::new();
+            loop {
+                checkpoint.tick();
+            }
+        };
+        let token = CancellationToken::with_timeout(Duration::from_millis(10));
+        let payload = std::panic::catch_unwind(|| token.run(spin)).unwrap_err();
+        let timeout = Some(Duration::from_millis(10));
+        assert_eq!(Some(&Cancelled { timeout }), payload.downcast_ref());
+
+        let token = CancellationToken::new();
+        token.run(check);
+        token.cancel();
+        assert!(std::panic::catch_unwind(|| token.run(check)).is_err());
+        // Outside of `run`, nothing is cancelled.
+        check();
+    }
+}
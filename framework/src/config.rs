@@ -0,0 +1,145 @@
+use crate::{error::Error, inputs::InputKey, result::Result};
+use std::path::PathBuf;
+
+const CONFIG_FILE: &str = "./aoc.toml";
+const DEFAULT_YEAR: u32 = 2023;
+
+/// Settings for downloading and storing inputs. They're read from the
+/// top-level keys of `aoc.toml` (or the file named by `AOC_CONFIG`), and can
+/// be overridden by the `AOC_YEAR`, `AOC_BASE_URL`,
+/// `AOC_SESSION_KEY_FILE` and `AOC_INPUT_KEY_FILE` environment variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub year: u32,
+    /// Root of the website, without the year.
+    pub base_url: String,
+    /// File containing the session cookie used to download inputs.
+    pub session_key_file: PathBuf,
+    /// File containing the key that inputs are encrypted with, as 64 hex
+    /// digits.
+    pub input_key_file: Option<PathBuf>,
+    /// The key itself, only set from the `AOC_INPUT_KEY` environment
+    /// variable, such as in CI. Takes precedence over the file.
+    pub input_key: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: DEFAULT_YEAR,
+            base_url: "https://adventofcode.com".to_owned(),
+            session_key_file: PathBuf::from("./session_key.txt"),
+            input_key_file: None,
+            input_key: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Config> {
+        let env = |name: &str| std::env::var(name).ok();
+        let path = env("AOC_CONFIG");
+        let file = match std::fs::read_to_string(path.as_deref().unwrap_or(CONFIG_FILE)) {
+            Ok(contents) => Some(contents),
+            // The default config file is optional, an explicitly named one isn't.
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && path.is_none() => None,
+            Err(e) => return Err(e.into()),
+        };
+        Config::from_sources(file.as_deref(), env)
+    }
+
+    fn from_sources(file: Option<&str>, env: impl Fn(&str) -> Option<String>) -> Result<Config> {
+        let mut config = Config::default();
+        let table = parse_toml(file.unwrap_or_default()).map_err(Error::InvalidConfig)?;
+        for (key, value) in &table {
+            let value = match value {
+                toml::Value::String(value) => value.clone(),
+                toml::Value::Integer(value) => value.to_string(),
+                _ => return Err(Error::InvalidConfig(format!("invalid value of '{key}'"))),
+            };
+            config.set(key, &value).map_err(Error::InvalidConfig)?;
+        }
+
+        for (key, var) in [
+            ("year", "AOC_YEAR"),
+            ("base_url", "AOC_BASE_URL"),
+            ("session_key_file", "AOC_SESSION_KEY_FILE"),
+            ("input_key_file", "AOC_INPUT_KEY_FILE"),
+        ] {
+            if let Some(value) = env(var) {
+                config
+                    .set(key, &value)
+                    .map_err(|e| Error::InvalidConfig(format!("{var}: {e}")))?;
+            }
+        }
+        config.input_key = env("AOC_INPUT_KEY");
+        Ok(config)
+    }
+
+    /// The key that inputs are encrypted with, if one is configured.
+    pub fn input_key(&self) -> Result<Option<InputKey>> {
+        if let Some(secret) = &self.input_key {
+            return InputKey::from_hex(secret).map(Some);
+        }
+        match &self.input_key_file {
+            Some(path) => InputKey::from_hex(&std::fs::read_to_string(path)?).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> std::result::Result<(), String> {
+        match key {
+            "year" => {
+                self.year = value
+                    .parse()
+                    .map_err(|_| format!("invalid year '{value}'"))?
+            }
+            "base_url" => value.trim_end_matches('/').clone_into(&mut self.base_url),
+            "session_key_file" => self.session_key_file = PathBuf::from(value),
+            "input_key_file" => self.input_key_file = Some(PathBuf::from(value)),
+            _ => return Err(format!("unknown key '{key}'")),
+        }
+        Ok(())
+    }
+}
+
+/// Parses a TOML document, describing errors by the line they're on.
+pub(crate) fn parse_toml(contents: &str) -> std::result::Result<toml::Table, String> {
+    contents.parse().map_err(|e: toml::de::Error| {
+        let line = e
+            .span()
+            .map_or(1, |span| contents[..span.start].matches('\n').count() + 1);
+        format!("line {line}: {}", e.message())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sources() {
+        let file = "# comment\nyear = 2022\n\nbase_url = \"http://localhost:8080/\" # mock\n";
+        let config = Config::from_sources(Some(file), |_| None).unwrap();
+        assert_eq!(2022, config.year);
+        assert_eq!("http://localhost:8080", config.base_url);
+        assert_eq!(PathBuf::from("./session_key.txt"), config.session_key_file);
+
+        let env = |name: &str| (name == "AOC_YEAR").then(|| "2024".to_owned());
+        assert_eq!(2024, Config::from_sources(Some(file), env).unwrap().year);
+
+        let file = "session_key_file = \"keys/#1.txt\" # quoted\n";
+        let config = Config::from_sources(Some(file), |_| None).unwrap();
+        assert_eq!(PathBuf::from("keys/#1.txt"), config.session_key_file);
+
+        let error = Config::from_sources(Some("year = 2023\nyaer = 2023"), |_| None).unwrap_err();
+        assert_eq!("invalid config: unknown key 'yaer'", error.to_string());
+        let error = Config::from_sources(Some("year = 2023\nyear 2024"), |_| None).unwrap_err();
+        assert!(error.to_string().starts_with("invalid config: line 2: "));
+        let error = Config::from_sources(None, |_| Some("soon".to_owned())).unwrap_err();
+        assert_eq!(
+            "invalid config: AOC_YEAR: invalid year 'soon'",
+            error.to_string()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::parsers::Location;
+use std::{borrow::Cow, fmt};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -11,7 +13,139 @@ pub enum Error {
     #[error("not yet implemented")]
     NotImplemented,
     #[error("invalid input '{0}'")]
-    InvalidInput(&'static str),
+    InvalidInput(Cow<'static, str>),
+    #[error("not a puzzle input, {0}")]
+    NotAnInput(&'static str),
+    #[error("these inputs can't be downloaded with this session")]
+    NotDownloadable,
+    #[error("encryption error: {0}")]
+    Encryption(&'static str),
     #[error("no solution")]
     NoSolution,
+    #[error("panicked at {0}")]
+    Panic(String),
+    #[error("timed out after {0:?}")]
+    TimedOut(std::time::Duration),
+    #[error("cancelled")]
+    Cancelled,
+    #[error("invalid config: {0}")]
+    InvalidConfig(String),
+    #[error("not submitted, {0}")]
+    SubmissionRefused(#[from] crate::submissions::Refusal),
+    /// An error with context added by [`ResultExt::context`], rendered as
+    /// `context: error`.
+    ///
+    /// [`ResultExt::context`]: crate::result::ResultExt::context
+    #[error("{0}: {1}")]
+    WithContext(Context, Box<Error>),
+}
+
+/// Where, or while doing what, an error happened.
+#[derive(Debug)]
+pub enum Context {
+    Day(u32),
+    /// The name of a part, or `parse`.
+    Part(&'static str),
+    /// A 1-based position in an input or file.
+    Position {
+        line: usize,
+        column: Option<usize>,
+    },
+    Message(Cow<'static, str>),
+}
+
+impl Context {
+    pub fn line(line: usize) -> Context {
+        Context::Position { line, column: None }
+    }
+
+    pub fn position(line: usize, column: usize) -> Context {
+        Context::Position {
+            line,
+            column: Some(column),
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Context::Day(day) => write!(f, "day {day}"),
+            Context::Part(part) => f.write_str(part),
+            Context::Position { line, column: None } => write!(f, "line {line}"),
+            Context::Position {
+                line,
+                column: Some(column),
+            } => write!(f, "line {line}, column {column}"),
+            Context::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<&'static str> for Context {
+    fn from(message: &'static str) -> Self {
+        Context::Message(message.into())
+    }
+}
+
+impl From<String> for Context {
+    fn from(message: String) -> Self {
+        Context::Message(message.into())
+    }
+}
+
+impl Error {
+    pub fn context(self, context: impl Into<Context>) -> Error {
+        Error::WithContext(context.into(), Box::new(self))
+    }
+
+    /// The error without any of the context added to it.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::WithContext(_, error) => error.root(),
+            error => error,
+        }
+    }
+
+    /// The context added to the error, outermost first.
+    pub fn contexts(&self) -> impl Iterator<Item = &Context> {
+        let mut error = self;
+        std::iter::from_fn(move || match error {
+            Error::WithContext(context, inner) => {
+                error = inner;
+                Some(context)
+            }
+            _ => None,
+        })
+    }
+
+    /// Where in the input parsing failed, for parse errors.
+    pub fn location(&self) -> Option<&Location> {
+        match self.root() {
+            Error::ParseError(e) => e.location(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::result::{Result, ResultExt};
+
+    #[test]
+    fn context() {
+        let result: Result<()> = Err(Error::InvalidInput(format!("value {}", 5).into()));
+        let error = result
+            .context(Context::position(3, 7))
+            .context(Context::Day(2))
+            .unwrap_err();
+        assert_eq!(
+            "day 2: line 3, column 7: invalid input 'value 5'",
+            error.to_string()
+        );
+        assert!(matches!(error.root(), Error::InvalidInput(_)));
+        let contexts = error.contexts().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(["day 2", "line 3, column 7"], &contexts[..]);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::cancel::Checkpoint;
 use ahash::RandomState;
 use std::collections::VecDeque;
 use std::hash::Hash;
@@ -36,13 +37,16 @@ nodes_container!(BfsNodes, VecDeque, push_back);
 /// Cyclic graphs will result in a hang.
 /// Return Some(value) at any point to halt the process.
 /// Child nodes added during each visit are visited in reverse insertion order.
+/// Stops when the runner cancels the current part.
 pub fn dfs<N, O, F>(init: N, mut visit: F) -> Option<O>
 where
     F: FnMut(N, &mut DfsNodes<N>) -> Option<O>,
 {
     let mut nodes = DfsNodes { data: Vec::new() };
     nodes.data.push(init);
+    let mut checkpoint = Checkpoint::new();
     while let Some(node) = nodes.data.pop() {
+        checkpoint.tick();
         if let Some(result) = visit(node, &mut nodes) {
             return Some(result);
         }
@@ -58,7 +62,9 @@ where
         data: VecDeque::new(),
     };
     nodes.push(init);
+    let mut checkpoint = Checkpoint::new();
     while let Some(node) = nodes.data.pop_front() {
+        checkpoint.tick();
         if let Some(output) = visit(node, &mut nodes) {
             return Some(output);
         }
@@ -117,7 +123,9 @@ where
     });
     let mut visited = HashMap::<N, (C, Option<N>)>::with_hasher(RandomState::new());
     let mut next_nodes = Vec::new();
+    let mut checkpoint = Checkpoint::new();
     while let Some(entry) = pending.pop() {
+        checkpoint.tick();
         if is_target(&entry.node) {
             let total_cost = entry.cost;
             let mut path = Vec::new();
@@ -203,7 +211,9 @@ where
         });
     }
     let mut visited = HashMap::<N, C>::with_hasher(RandomState::new());
+    let mut checkpoint = Checkpoint::new();
     while let Some(entry) = pending.pop() {
+        checkpoint.tick();
         if is_target(&entry.node) {
             return Some(entry.cost);
         }
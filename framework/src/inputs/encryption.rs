@@ -0,0 +1,107 @@
+use crate::{error::Error, result::Result};
+use ring::{
+    aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    rand::{SecureRandom, SystemRandom},
+};
+use std::fmt;
+
+/// Start of every encrypted input, to recognize the format.
+const MAGIC: &[u8] = b"AOCENC1\n";
+
+/// Key used to store inputs encrypted, with ChaCha20-Poly1305.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InputKey {
+    bytes: [u8; 32],
+}
+
+impl fmt::Debug for InputKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InputKey(..)")
+    }
+}
+
+impl InputKey {
+    /// Reads a key written as 64 hex digits, such as generated by
+    /// `openssl rand -hex 32`. Surrounding whitespace is ignored.
+    ///
+    /// Passphrases aren't accepted, encrypted inputs are published, so a
+    /// guessable key could be brute-forced offline.
+    pub fn from_hex(secret: &str) -> Result<InputKey> {
+        let invalid = || Error::Encryption("the input key must be 64 hex digits");
+        let secret = secret.trim().as_bytes();
+        if secret.len() != 64 {
+            return Err(invalid());
+        }
+        let digit = |c: u8| char::from(c).to_digit(16).ok_or_else(invalid);
+        let mut bytes = [0; 32];
+        for (byte, pair) in bytes.iter_mut().zip(secret.chunks(2)) {
+            *byte = (digit(pair[0])? * 16 + digit(pair[1])?) as u8;
+        }
+        Ok(InputKey { bytes })
+    }
+
+    fn key(&self) -> LessSafeKey {
+        LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, &self.bytes).unwrap())
+    }
+
+    pub fn encrypt(&self, input: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| Error::Encryption("no random numbers available"))?;
+        let mut data = input.to_vec();
+        self.key()
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+            .map_err(|_| Error::Encryption("encryption failed"))?;
+        Ok([MAGIC, &nonce, &data].concat())
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|data| data.len() >= NONCE_LEN)
+            .ok_or(Error::Encryption("not an encrypted input"))?;
+        let (nonce, data) = data.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).unwrap();
+        let mut data = data.to_vec();
+        let len = self
+            .key()
+            .open_in_place(nonce, Aad::empty(), &mut data)
+            .map_err(|_| Error::Encryption("decryption failed, the key may be wrong"))?
+            .len();
+        data.truncate(len);
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191A1B1C1D1E1F";
+        let key = InputKey::from_hex(&format!("{KEY}\n")).unwrap();
+        assert_eq!((0..32).collect::<Vec<u8>>(), key.bytes);
+        let encrypted = key.encrypt(b"1 2 3\n").unwrap();
+        assert!(encrypted.starts_with(MAGIC));
+        assert_eq!(b"1 2 3\n".to_vec(), key.decrypt(&encrypted).unwrap());
+        // Every encryption uses a different nonce.
+        assert_ne!(encrypted, key.encrypt(b"1 2 3\n").unwrap());
+
+        let other = InputKey::from_hex(&KEY.replace('0', "f")).unwrap();
+        assert!(other.decrypt(&encrypted).is_err());
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(key.decrypt(&tampered).is_err());
+        assert!(key.decrypt(b"1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn invalid_keys() {
+        assert!(InputKey::from_hex("correct horse battery staple").is_err());
+        assert!(InputKey::from_hex(&"0".repeat(62)).is_err());
+        assert!(InputKey::from_hex(&format!("{}+f", "0".repeat(62))).is_err());
+        assert!(InputKey::from_hex(&format!("{}é", "0".repeat(62))).is_err());
+    }
+}
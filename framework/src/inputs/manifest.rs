@@ -0,0 +1,111 @@
+use crate::{config::parse_toml, error::Error, result::Result};
+use ring::digest::{SHA256, digest};
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf};
+
+/// Whether an input still matches the one that was downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrity {
+    Unchanged,
+    Modified,
+    /// There's no input to check.
+    Missing,
+    /// The input wasn't downloaded, or was before hashes were recorded.
+    Unknown,
+}
+
+impl Integrity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Integrity::Unchanged => "unchanged",
+            Integrity::Modified => "modified",
+            Integrity::Missing => "missing",
+            Integrity::Unknown => "unknown",
+        }
+    }
+}
+
+/// SHA-256 hashes of the inputs as they were downloaded, stored in the
+/// inputs directory as `manifest.toml`, with a `NN = "hash"` line per day.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    path: PathBuf,
+}
+
+impl Manifest {
+    pub fn new(path: impl Into<PathBuf>) -> Manifest {
+        Manifest { path: path.into() }
+    }
+
+    fn read(&self) -> Result<BTreeMap<u32, String>> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let table = parse_toml(&contents)
+            .map_err(|e| Error::InvalidInput(format!("{e} in input manifest").into()))?;
+        table
+            .into_iter()
+            .map(|(key, hash)| {
+                let invalid = || {
+                    Error::InvalidInput(format!("invalid entry '{key}' in input manifest").into())
+                };
+                let day = key.parse().map_err(|_| invalid())?;
+                let hash = hash.as_str().ok_or_else(invalid)?.to_owned();
+                Ok((day, hash))
+            })
+            .collect()
+    }
+
+    /// Records the hash of an input of a day, replacing any previous one.
+    pub fn record(&self, day: u32, input: &[u8]) -> Result<()> {
+        let mut hashes = self.read()?;
+        hashes.insert(day, hash(input));
+
+        let mut contents = "# SHA-256 hashes of the inputs as they were downloaded.\n".to_owned();
+        for (day, hash) in hashes {
+            _ = writeln!(contents, "{day:0>2} = \"{hash}\"");
+        }
+        std::fs::write(&self.path, contents)?;
+        Ok(())
+    }
+
+    pub fn verify(&self, day: u32, input: &[u8]) -> Result<Integrity> {
+        Ok(match self.read()?.get(&day) {
+            Some(expected) if *expected == hash(input) => Integrity::Unchanged,
+            Some(_) => Integrity::Modified,
+            None => Integrity::Unknown,
+        })
+    }
+}
+
+fn hash(input: &[u8]) -> String {
+    let mut hex = String::with_capacity(64);
+    for byte in digest(&SHA256, input).as_ref() {
+        _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn manifest() {
+        let path = std::env::temp_dir().join(format!("aoc_manifest_{}", std::process::id()));
+        let manifest = Manifest::new(&path);
+        assert_eq!(Integrity::Unknown, manifest.verify(3, b"abc").unwrap());
+        manifest.record(3, b"abc").unwrap();
+        manifest.record(12, b"def").unwrap();
+        assert_eq!(Integrity::Unchanged, manifest.verify(3, b"abc").unwrap());
+        assert_eq!(Integrity::Modified, manifest.verify(12, b"abc").unwrap());
+        assert_eq!(
+            "# SHA-256 hashes of the inputs as they were downloaded.\n\
+             03 = \"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\"\n\
+             12 = \"cb8379ac2098aa165029e3938a51da0bcecfc008fd6795f401178647f96c5b34\"\n",
+            std::fs::read_to_string(&path).unwrap()
+        );
+        _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,790 @@
+pub mod encryption;
+pub mod manifest;
+pub mod normalize;
+
+pub use self::{
+    encryption::InputKey,
+    manifest::{Integrity, Manifest},
+    normalize::{DEFAULT_NORMALIZERS, Normalizer},
+};
+use crate::{config::Config, error::Error, result::Result};
+use std::{
+    fmt::Debug,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const MINIMUM_TIME_BETWEEN_DOWNLOADS: Duration = Duration::from_secs(3);
+/// Stores the time of the last download, so that separate runs are throttled
+/// as well.
+const THROTTLE_FILE: &str = "./.last_download";
+/// Number of times a download is attempted when it fails with a transient
+/// error, such as a timeout or a server error.
+const MAX_ATTEMPTS: u32 = 4;
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+const EXAMPLES_DIR: &str = "./inputs/examples";
+
+/// Name of the profile whose inputs are stored directly in the inputs
+/// directory, rather than in a subdirectory.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Where puzzle inputs are read from.
+pub trait InputSource: Debug + Send + Sync {
+    /// Returns the input of a day, or `None` if it should be downloaded.
+    fn read(&self, day: u32) -> Result<Option<Vec<u8>>>;
+
+    /// Whether missing or modified inputs may be downloaded with the
+    /// configured session.
+    fn can_download(&self) -> bool {
+        true
+    }
+
+    /// Stores a freshly downloaded input.
+    fn store(&self, _day: u32, _input: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Checks whether the stored input still matches the downloaded one.
+    fn verify(&self, _day: u32) -> Result<Integrity> {
+        Ok(Integrity::Unknown)
+    }
+
+    /// Accepts the stored input as if it was downloaded, such as after
+    /// editing it on purpose.
+    fn trust(&self, _day: u32) -> Result<()> {
+        Ok(())
+    }
+
+    /// Replaces the stored plaintext input with an encrypted one.
+    fn encrypt(&self, _day: u32) -> Result<()> {
+        Err(Error::Encryption("these inputs can't be stored encrypted"))
+    }
+
+    /// Files that the input of a day is read from, whether they exist or not.
+    fn files(&self, _day: u32) -> Vec<PathBuf> {
+        Vec::new()
+    }
+}
+
+/// Inputs stored as `NN.txt` files in a directory, or when encrypted, as
+/// `NN.enc` files.
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    dir: PathBuf,
+    download: bool,
+    key: Option<InputKey>,
+}
+
+impl DirectorySource {
+    pub fn new(dir: impl Into<PathBuf>) -> DirectorySource {
+        DirectorySource {
+            dir: dir.into(),
+            download: true,
+            key: None,
+        }
+    }
+
+    /// Stores downloaded inputs encrypted with the key, and decrypts the
+    /// encrypted inputs. Plaintext inputs can still be read.
+    pub fn with_key(self, key: Option<InputKey>) -> DirectorySource {
+        DirectorySource { key, ..self }
+    }
+
+    /// Treats missing inputs as an error, instead of downloading them. Used
+    /// for the inputs of other accounts, which the session key can't fetch.
+    pub fn offline(self) -> DirectorySource {
+        DirectorySource {
+            download: false,
+            ..self
+        }
+    }
+
+    fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("{day:0>2}.txt"))
+    }
+
+    fn encrypted_path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("{day:0>2}.enc"))
+    }
+
+    fn manifest(&self) -> Manifest {
+        Manifest::new(self.dir.join("manifest.toml"))
+    }
+
+    fn key(&self) -> Result<&InputKey> {
+        self.key
+            .as_ref()
+            .ok_or(Error::Encryption("no input key is configured"))
+    }
+
+    /// Reads the input, decrypting it if necessary.
+    fn read_stored(&self, day: u32) -> Result<Option<Vec<u8>>> {
+        match std::fs::read(self.encrypted_path(day)) {
+            Ok(data) => return self.key()?.decrypt(&data).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        match std::fs::read(self.path(day)) {
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn read_existing(&self, day: u32) -> Result<Vec<u8>> {
+        self.read_stored(day)?.ok_or_else(|| {
+            let message = format!("{} doesn't exist", self.path(day).display());
+            std::io::Error::new(std::io::ErrorKind::NotFound, message).into()
+        })
+    }
+}
+
+impl InputSource for DirectorySource {
+    fn read(&self, day: u32) -> Result<Option<Vec<u8>>> {
+        match self.download {
+            true => self.read_stored(day),
+            false => self.read_existing(day).map(Some),
+        }
+    }
+
+    fn can_download(&self) -> bool {
+        self.download
+    }
+
+    fn store(&self, day: u32, input: &[u8]) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        match &self.key {
+            Some(key) => std::fs::write(self.encrypted_path(day), key.encrypt(input)?)?,
+            None => std::fs::write(self.path(day), input)?,
+        }
+        self.manifest().record(day, input)
+    }
+
+    fn verify(&self, day: u32) -> Result<Integrity> {
+        match self.read_stored(day)? {
+            Some(input) => self.manifest().verify(day, &input),
+            None => Ok(Integrity::Missing),
+        }
+    }
+
+    fn trust(&self, day: u32) -> Result<()> {
+        self.manifest().record(day, &self.read_existing(day)?)
+    }
+
+    fn encrypt(&self, day: u32) -> Result<()> {
+        let path = self.path(day);
+        let input = std::fs::read(&path)?;
+        std::fs::write(self.encrypted_path(day), self.key()?.encrypt(&input)?)?;
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    fn files(&self, day: u32) -> Vec<PathBuf> {
+        vec![self.encrypted_path(day), self.path(day)]
+    }
+}
+
+/// A single file, used as the input of whichever day is run.
+#[derive(Debug, Clone)]
+pub struct FileSource {
+    path: PathBuf,
+}
+
+impl FileSource {
+    pub fn new(path: impl Into<PathBuf>) -> FileSource {
+        FileSource { path: path.into() }
+    }
+}
+
+impl InputSource for FileSource {
+    fn read(&self, _day: u32) -> Result<Option<Vec<u8>>> {
+        Ok(Some(std::fs::read(&self.path)?))
+    }
+
+    fn can_download(&self) -> bool {
+        false
+    }
+
+    fn files(&self, _day: u32) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
+}
+
+/// Standard input, read once and used as the input of whichever day is run.
+#[derive(Debug, Default)]
+pub struct StdinSource {
+    input: OnceLock<Vec<u8>>,
+}
+
+impl InputSource for StdinSource {
+    fn read(&self, _day: u32) -> Result<Option<Vec<u8>>> {
+        if let Some(input) = self.input.get() {
+            return Ok(Some(input.clone()));
+        }
+        let mut input = Vec::new();
+        std::io::stdin().lock().read_to_end(&mut input)?;
+        Ok(Some(self.input.get_or_init(|| input).clone()))
+    }
+
+    fn can_download(&self) -> bool {
+        false
+    }
+}
+
+/// Provides the puzzle inputs, downloading them when they're missing. Can be
+/// shared between threads, downloads are serialized.
+#[derive(Debug)]
+pub struct Inputs {
+    source: Box<dyn InputSource>,
+    normalizers: Vec<Normalizer>,
+    /// Cache of the examples from the puzzle descriptions.
+    examples_dir: PathBuf,
+    downloader: Mutex<Downloader>,
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Inputs::with_source(DirectorySource::new("./inputs"))
+    }
+}
+
+/// Lists the profiles in an inputs directory, which are the subdirectories
+/// containing `NN.txt` or `NN.enc` files, and the directory itself as
+/// [`DEFAULT_PROFILE`] if it contains any.
+pub fn profiles(dir: &Path) -> Result<Vec<String>> {
+    let has_inputs = |dir: &Path| -> Result<bool> {
+        for entry in std::fs::read_dir(dir)? {
+            let name = entry?.file_name();
+            let name = name.as_encoded_bytes();
+            if let [a, b, b'.', extension @ ..] = name
+                && a.is_ascii_digit()
+                && b.is_ascii_digit()
+                && (extension == b"txt" || extension == b"enc")
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    };
+
+    let mut profiles = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && let Some(name) = entry.file_name().to_str()
+            && name != DEFAULT_PROFILE
+            && has_inputs(&entry.path())?
+        {
+            profiles.push(name.to_owned());
+        }
+    }
+    profiles.sort();
+    if has_inputs(dir)? {
+        profiles.insert(0, DEFAULT_PROFILE.to_owned());
+    }
+    Ok(profiles)
+}
+
+impl Inputs {
+    pub fn new() -> Inputs {
+        Default::default()
+    }
+
+    pub fn with_source(source: impl InputSource + 'static) -> Inputs {
+        Inputs {
+            source: Box::new(source),
+            normalizers: DEFAULT_NORMALIZERS.to_vec(),
+            examples_dir: PathBuf::from(EXAMPLES_DIR),
+            downloader: Mutex::new(Downloader::new(&Config::default())),
+        }
+    }
+
+    /// The inputs in `./inputs`, decrypted with the configured key, and
+    /// downloaded from the configured website and year when missing.
+    pub fn from_config(config: &Config) -> Result<Inputs> {
+        let source = DirectorySource::new("./inputs").with_key(config.input_key()?);
+        Ok(Inputs::with_source(source).with_config(config))
+    }
+
+    /// Downloads missing inputs from the configured website and year.
+    pub fn with_config(mut self, config: &Config) -> Inputs {
+        self.downloader = Mutex::new(Downloader::new(config));
+        self
+    }
+
+    /// Replaces the functions applied to every input before it's parsed.
+    pub fn with_normalizers(mut self, normalizers: &[Normalizer]) -> Inputs {
+        self.normalizers = normalizers.to_vec();
+        self
+    }
+
+    pub fn get(&self, day: u32) -> Result<Vec<u8>> {
+        let mut input = match self.source.read(day)? {
+            Some(input) => input,
+            None => self.download(day)?,
+        };
+        self.normalize(&mut input);
+        normalize::check(&input)?;
+        Ok(input)
+    }
+
+    fn normalize(&self, input: &mut Vec<u8>) {
+        for normalize in &self.normalizers {
+            normalize(input);
+        }
+    }
+
+    fn download(&self, day: u32) -> Result<Vec<u8>> {
+        let mut downloader = self.downloader.lock().unwrap_or_else(|e| e.into_inner());
+        // Another thread may have downloaded it while waiting for the lock.
+        if let Some(input) = self.source.read(day)? {
+            return Ok(input);
+        }
+        self.download_with(&mut downloader, day)
+    }
+
+    fn download_with(&self, downloader: &mut Downloader, day: u32) -> Result<Vec<u8>> {
+        let input = downloader.download(day)?;
+        // Error pages aren't stored, so that the next run tries again.
+        normalize::check(&input)?;
+        self.source.store(day, &input)?;
+        Ok(input)
+    }
+
+    /// Whether inputs may be downloaded, which isn't the case for another
+    /// account's inputs.
+    pub fn can_download(&self) -> bool {
+        self.source.can_download()
+    }
+
+    /// Downloads an input again, replacing the stored one.
+    pub fn refetch(&self, day: u32) -> Result<()> {
+        if !self.can_download() {
+            return Err(Error::NotDownloadable);
+        }
+        let mut downloader = self.downloader.lock().unwrap_or_else(|e| e.into_inner());
+        self.download_with(&mut downloader, day)?;
+        Ok(())
+    }
+
+    /// Checks whether the stored input still matches the downloaded one.
+    pub fn verify(&self, day: u32) -> Result<Integrity> {
+        self.source.verify(day)
+    }
+
+    /// Accepts the stored input as if it was downloaded.
+    pub fn trust(&self, day: u32) -> Result<()> {
+        self.source.trust(day)
+    }
+
+    /// Replaces the stored plaintext input with an encrypted one.
+    pub fn encrypt(&self, day: u32) -> Result<()> {
+        self.source.encrypt(day)
+    }
+
+    /// Files that the input of a day is read from, to watch for changes.
+    pub fn files(&self, day: u32) -> Vec<PathBuf> {
+        self.source.files(day)
+    }
+
+    /// Submits an answer to the website, returning the response page.
+    pub fn submit(&self, day: u32, level: u32, answer: &str) -> Result<String> {
+        let mut downloader = self.downloader.lock().unwrap_or_else(|e| e.into_inner());
+        let page = downloader.submit(day, level, answer)?;
+        Ok(String::from_utf8_lossy(&page).into_owned())
+    }
+
+    /// Returns the `index`th example (starting at 1) of a puzzle description,
+    /// downloading the puzzle page when it isn't cached yet. Examples of the
+    /// second part only appear once the first part is solved, in which case
+    /// the page is downloaded again.
+    pub fn example(&self, day: u32, index: usize) -> Result<Vec<u8>> {
+        let path = self.examples_dir.join(format!("{day:0>2}-{index}.txt"));
+        if !path.exists() {
+            let mut downloader = self.downloader.lock().unwrap_or_else(|e| e.into_inner());
+            if !path.exists() {
+                let page = downloader.page(day)?;
+                let examples = extract_examples(&String::from_utf8_lossy(&page));
+                std::fs::create_dir_all(&self.examples_dir)?;
+                for (i, example) in examples.iter().enumerate() {
+                    let path = self.examples_dir.join(format!("{day:0>2}-{}.txt", i + 1));
+                    std::fs::write(path, example)?;
+                }
+            }
+        }
+        let mut example = match std::fs::read(&path) {
+            Ok(example) => example,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::InvalidInput(
+                    "puzzle has no example with this index".into(),
+                ));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        self.normalize(&mut example);
+        Ok(example)
+    }
+}
+
+/// Extracts the contents of the `<pre><code>` blocks of a puzzle page.
+fn extract_examples(html: &str) -> Vec<String> {
+    const START: &str = "<pre><code>";
+    let mut examples = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(START) {
+        rest = &rest[start + START.len()..];
+        let Some(end) = rest.find("</code></pre>") else {
+            break;
+        };
+        examples.push(decode_html(&rest[..end]));
+        rest = &rest[end..];
+    }
+    examples
+}
+
+/// Strips tags, such as the `<em>` used for highlighting, and decodes the
+/// entities used on the puzzle pages.
+fn decode_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(i) = rest.find(['<', '&']) {
+        text.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with('<') {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let (c, len) = match entity {
+            Some(("lt", end)) => ('<', end + 1),
+            Some(("gt", end)) => ('>', end + 1),
+            Some(("amp", end)) => ('&', end + 1),
+            Some(("quot", end)) => ('"', end + 1),
+            Some(("#39" | "#x27" | "apos", end)) => ('\'', end + 1),
+            _ => ('&', 1),
+        };
+        text.push(c);
+        rest = &rest[len..];
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Downloads inputs from the Advent of Code website, retrying transient
+/// failures, and keeping a minimum time between requests.
+#[derive(Debug)]
+struct Downloader {
+    /// URL of the puzzles of a year, such as `https://adventofcode.com/2023`.
+    base_url: String,
+    session_key_file: PathBuf,
+    session_key: Option<String>,
+    throttle: Throttle,
+    /// Delay before the first retry, doubled for every subsequent one.
+    retry_delay: Duration,
+}
+
+impl Downloader {
+    fn new(config: &Config) -> Downloader {
+        Downloader {
+            base_url: format!("{}/{}", config.base_url, config.year),
+            session_key_file: config.session_key_file.clone(),
+            session_key: None,
+            throttle: Throttle {
+                path: Some(PathBuf::from(THROTTLE_FILE)),
+                interval: MINIMUM_TIME_BETWEEN_DOWNLOADS,
+                last: None,
+            },
+            retry_delay: RETRY_DELAY,
+        }
+    }
+}
+
+/// Enforces a minimum time between requests, also across separate runs, by
+/// storing the time of the last request on disk.
+#[derive(Debug)]
+struct Throttle {
+    path: Option<PathBuf>,
+    interval: Duration,
+    last: Option<SystemTime>,
+}
+
+impl Throttle {
+    fn last_request(&self) -> Option<SystemTime> {
+        let stored = self
+            .path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| contents.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        self.last.max(stored)
+    }
+
+    /// Sleeps until the next request is allowed, and records it as made.
+    fn wait(&mut self) {
+        if let Some(last) = self.last_request() {
+            // A time in the future means the clock was changed, in which case
+            // the full interval is waited.
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if let Some(remaining) = self.interval.checked_sub(elapsed) {
+                std::thread::sleep(remaining);
+            }
+        }
+        let now = SystemTime::now();
+        self.last = Some(now);
+        if let Some(path) = &self.path {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            // Failing to store it only affects other runs, so it's not fatal.
+            _ = std::fs::write(path, millis.to_string());
+        }
+    }
+}
+
+impl Downloader {
+    fn get_session_key(&mut self) -> Result<&str> {
+        if self.session_key.is_none() {
+            let session_key = std::fs::read_to_string(&self.session_key_file)?;
+            self.session_key = Some(session_key.trim().to_owned());
+        }
+        Ok(self.session_key.as_ref().unwrap())
+    }
+
+    fn download(&mut self, day: u32) -> Result<Vec<u8>> {
+        let cookie = format!("session={}", self.get_session_key()?);
+        self.request(&format!("/day/{day}/input"), Some(&cookie))
+    }
+
+    /// Downloads the puzzle description. Logging in is optional, but without
+    /// it, the second part isn't included.
+    fn page(&mut self, day: u32) -> Result<Vec<u8>> {
+        let cookie = self
+            .get_session_key()
+            .ok()
+            .map(|session_key| format!("session={session_key}"));
+        self.request(&format!("/day/{day}"), cookie.as_deref())
+    }
+
+    fn submit(&mut self, day: u32, level: u32, answer: &str) -> Result<Vec<u8>> {
+        let cookie = format!("session={}", self.get_session_key()?);
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let level = level.to_string();
+        self.throttle.wait();
+        // Not retried, the answer may have been received despite the error.
+        let page = ureq::post(&url)
+            .header("cookie", &cookie)
+            .config()
+            .timeout_global(Some(Duration::from_secs(5)))
+            .build()
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .and_then(|response| response.into_body().read_to_vec())
+            .map_err(Box::new)?;
+        Ok(page)
+    }
+
+    fn request(&mut self, path: &str, cookie: Option<&str>) -> Result<Vec<u8>> {
+        let url = format!("{}{path}", self.base_url);
+        let mut attempt = 1;
+        loop {
+            self.throttle.wait();
+            match fetch(&url, cookie) {
+                Ok(input) => return Ok(input),
+                Err(e) if attempt < MAX_ATTEMPTS && is_transient(&e) => {
+                    std::thread::sleep(self.retry_delay * 2u32.pow(attempt - 1));
+                    attempt += 1;
+                }
+                Err(e) => return Err(Box::new(e).into()),
+            }
+        }
+    }
+}
+
+fn fetch(url: &str, cookie: Option<&str>) -> std::result::Result<Vec<u8>, ureq::Error> {
+    let mut request = ureq::get(url);
+    if let Some(cookie) = cookie {
+        request = request.header("cookie", cookie);
+    }
+    request
+        .config()
+        .timeout_global(Some(Duration::from_secs(5)))
+        .build()
+        .call()?
+        .into_body()
+        .read_to_vec()
+}
+
+/// Whether a failed request may succeed when retried.
+fn is_transient(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::StatusCode(status) => *status == 429 || *status >= 500,
+        ureq::Error::Io(_) | ureq::Error::Timeout(_) | ureq::Error::ConnectionFailed => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Instant,
+    };
+
+    const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello";
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const NOT_FOUND: &str =
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    /// Serves the responses in order, one per connection. Returns the base URL
+    /// and a handle yielding the request lines, followed by the body if there
+    /// is one.
+    fn serve(responses: &'static [&'static str]) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut read_line = || {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    line.trim_end().to_owned()
+                };
+                let mut request = read_line();
+                let mut content_length = 0;
+                loop {
+                    let header = read_line().to_ascii_lowercase();
+                    if header.is_empty() {
+                        break;
+                    }
+                    if let Some(length) = header.strip_prefix("content-length: ") {
+                        content_length = length.parse().unwrap();
+                    }
+                }
+                if content_length != 0 {
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    request.push(' ');
+                    request.push_str(&String::from_utf8(body).unwrap());
+                }
+                requests.push(request);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn downloader(base_url: String, throttle_path: Option<PathBuf>) -> Downloader {
+        Downloader {
+            base_url,
+            session_key_file: PathBuf::new(),
+            session_key: Some("key".to_owned()),
+            throttle: Throttle {
+                path: throttle_path,
+                interval: Duration::from_millis(50),
+                last: None,
+            },
+            retry_delay: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn retries() {
+        let (url, server) = serve(&[UNAVAILABLE, UNAVAILABLE, OK]);
+        let start = Instant::now();
+        assert_eq!(b"hello", &downloader(url, None).download(7).unwrap()[..]);
+        // Retries are throttled too.
+        assert!(start.elapsed() >= Duration::from_millis(90));
+        assert_eq!(vec!["GET /day/7/input HTTP/1.1"; 3], server.join().unwrap());
+
+        let (url, server) = serve(&[NOT_FOUND]);
+        assert!(downloader(url, None).download(7).is_err());
+        assert_eq!(1, server.join().unwrap().len());
+    }
+
+    #[test]
+    fn examples() {
+        const PAGE: &str = "<p>For example:</p>\n<pre><code>1 &lt; <em>2</em>\n3 &amp;&amp; 4\n</code></pre>\n\
+            <p>With <code>inline</code> code.</p>\n<pre><code>second\n</code></pre>";
+        let response: &'static str = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{PAGE}",
+            PAGE.len()
+        )
+        .leak();
+        let (url, server) = serve(Box::leak(Box::new([response])));
+        let dir = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        let inputs = Inputs {
+            source: Box::new(FileSource::new("")),
+            normalizers: DEFAULT_NORMALIZERS.to_vec(),
+            examples_dir: dir.clone(),
+            downloader: Mutex::new(downloader(url, None)),
+        };
+        assert_eq!(b"1 < 2\n3 && 4\n", &inputs.example(5, 1).unwrap()[..]);
+        // Cached by the first call.
+        assert_eq!(b"second\n", &inputs.example(5, 2).unwrap()[..]);
+        assert_eq!(vec!["GET /day/5 HTTP/1.1"], server.join().unwrap());
+        _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn bad_downloads() {
+        const LOGGED_OUT: &str =
+            "HTTP/1.1 200 OK\r\nContent-Length: 14\r\nConnection: close\r\n\r\nPlease log in\n";
+        let (url, server) = serve(&[LOGGED_OUT]);
+        let dir = std::env::temp_dir().join(format!("aoc_bad_downloads_{}", std::process::id()));
+        let inputs = Inputs {
+            source: Box::new(DirectorySource::new(&dir)),
+            normalizers: DEFAULT_NORMALIZERS.to_vec(),
+            examples_dir: PathBuf::new(),
+            downloader: Mutex::new(downloader(url, None)),
+        };
+        assert!(matches!(inputs.get(1), Err(Error::NotAnInput(_))));
+        assert!(!dir.join("01.txt").exists());
+        server.join().unwrap();
+
+        // Another account's inputs aren't replaced with this session's.
+        let inputs = Inputs::with_source(DirectorySource::new(&dir).offline());
+        assert!(matches!(inputs.refetch(1), Err(Error::NotDownloadable)));
+    }
+
+    #[test]
+    fn submit() {
+        const PAGE: &str = "HTTP/1.1 200 OK\r\nContent-Length: 26\r\nConnection: close\r\n\r\nThat's the right answer!\n\n";
+        let (url, server) = serve(&[PAGE]);
+        let inputs = Inputs {
+            source: Box::new(FileSource::new("")),
+            normalizers: DEFAULT_NORMALIZERS.to_vec(),
+            examples_dir: PathBuf::new(),
+            downloader: Mutex::new(downloader(url, None)),
+        };
+        let page = inputs.submit(4, 2, "12 34").unwrap();
+        assert_eq!("That's the right answer!\n\n", page);
+        assert_eq!(
+            vec!["POST /day/4/answer HTTP/1.1 level=2&answer=12+34"],
+            server.join().unwrap()
+        );
+    }
+
+    #[test]
+    fn persistent_throttle() {
+        let path = std::env::temp_dir().join(format!("aoc_throttle_{}", std::process::id()));
+        let (url, server) = serve(&[OK, OK]);
+        downloader(url.clone(), Some(path.clone()))
+            .download(1)
+            .unwrap();
+        // A new downloader, as used by a separate run, still has to wait.
+        let start = Instant::now();
+        downloader(url, Some(path.clone())).download(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        server.join().unwrap();
+        _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::error::Error;
+
+/// Transforms an input before it's parsed.
+pub type Normalizer = fn(&mut Vec<u8>);
+
+/// Applied to every input, unless replaced with [`Inputs::with_normalizers`].
+///
+/// [`Inputs::with_normalizers`]: super::Inputs::with_normalizers
+pub const DEFAULT_NORMALIZERS: &[Normalizer] = &[strip_bom, strip_carriage_returns, trim_end];
+
+/// Only the start of an input is searched for signs of an error page.
+const CHECKED_LENGTH: usize = 1024;
+
+pub fn strip_bom(input: &mut Vec<u8>) {
+    if input.starts_with(b"\xEF\xBB\xBF") {
+        input.drain(..3);
+    }
+}
+
+pub fn strip_carriage_returns(input: &mut Vec<u8>) {
+    input.retain(|c| *c != b'\r');
+}
+
+/// Replaces trailing whitespace and empty lines with a single newline.
+pub fn trim_end(input: &mut Vec<u8>) {
+    let len = input.trim_ascii_end().len();
+    input.truncate(len);
+    if !input.is_empty() {
+        input.push(b'\n');
+    }
+}
+
+/// Removes trailing whitespace from every line. Not applied by default,
+/// because some puzzles pad their lines with spaces.
+pub fn trim_line_ends(input: &mut Vec<u8>) {
+    let mut trimmed = Vec::with_capacity(input.len());
+    for (i, line) in input.split(|c| *c == b'\n').enumerate() {
+        if i != 0 {
+            trimmed.push(b'\n');
+        }
+        trimmed.extend_from_slice(line.trim_ascii_end());
+    }
+    *input = trimmed;
+}
+
+/// Detects content that isn't a puzzle input, such as the pages served when
+/// the session key expired, or when a puzzle isn't unlocked yet.
+pub fn check(input: &[u8]) -> Result<(), Error> {
+    let start = &input[..input.len().min(CHECKED_LENGTH)];
+    let contains = |needle: &[u8]| start.windows(needle.len()).any(|w| w == needle);
+    if input.trim_ascii().is_empty() {
+        Err(Error::NotAnInput("it's empty"))
+    } else if contains(b"Please log in") {
+        Err(Error::NotAnInput(
+            "not logged in, the session key may have expired",
+        ))
+    } else if contains(b"before it unlocks") {
+        Err(Error::NotAnInput("the puzzle isn't unlocked yet"))
+    } else if is_html(start) {
+        Err(Error::NotAnInput("it's an HTML page"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Only checks for the start of a document, some inputs start with `<`.
+fn is_html(input: &[u8]) -> bool {
+    let start = input.trim_ascii_start();
+    let start = &start[..start.len().min(9)];
+    start.eq_ignore_ascii_case(b"<!doctype") || start.to_ascii_lowercase().starts_with(b"<html")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn normalize(input: &[u8], normalizers: &[Normalizer]) -> Vec<u8> {
+        let mut input = input.to_vec();
+        for normalize in normalizers {
+            normalize(&mut input);
+        }
+        input
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn normalizers() {
+        assert_eq!(b"1 2\n3\n".to_vec(), normalize(b"\xEF\xBB\xBF1 2\r\n3\r\n\r\n  ", DEFAULT_NORMALIZERS));
+        assert_eq!(b"1\n".to_vec(), normalize(b"1", DEFAULT_NORMALIZERS));
+        assert_eq!(b"".to_vec(), normalize(b"\n\n", DEFAULT_NORMALIZERS));
+        assert_eq!(b"  1\n\n2".to_vec(), normalize(b"  1  \n \n2\t", &[trim_line_ends]));
+    }
+
+    #[test]
+    fn checks() {
+        assert!(check(b"1 2\n3\n").is_ok());
+        assert!(check(b"<<>><>\n").is_ok());
+        assert!(matches!(check(b"\n"), Err(Error::NotAnInput(_))));
+        assert!(matches!(
+            check(b"<!DOCTYPE html>\n<html>"),
+            Err(Error::NotAnInput(_))
+        ));
+        let page = b"Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+        assert!(matches!(check(page), Err(Error::NotAnInput(_))));
+    }
+}
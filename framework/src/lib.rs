@@ -6,8 +6,11 @@
 #![feature(trait_alias)]
 #![feature(trusted_len)]
 
+pub mod answers;
 pub mod astr;
+pub mod cancel;
 pub mod cbuffer;
+pub mod config;
 pub mod error;
 pub mod graph;
 pub mod grid;
@@ -20,6 +23,7 @@ pub mod parsers;
 pub mod prelude;
 pub mod result;
 pub mod runner;
+pub mod submissions;
 pub mod util;
 pub mod vecs;
 
@@ -1,165 +1,321 @@
-use crate::astr::*;
-use colored::Colorize;
-use std::fmt::{self, Write};
+use crate::{astr::*, grid::BitGrid, util::display_width};
+use colored::{ColoredString, Colorize};
+use std::{borrow::Cow, fmt};
 
-#[derive(Debug)]
-pub struct ColoredOutput {
-    value: String,
-    control_count: usize,
+/// The answer of a part. Its structure is kept so it can be rendered for a
+/// terminal, as plain text, or as JSON, while only its final value is
+/// compared against expected answers, and submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// A single value, such as a number.
+    Scalar(String),
+    Text(String),
+    /// Operands combined into the final value, such as `3 * 4 = 12`.
+    Expression {
+        operator: Operator,
+        operands: Vec<String>,
+        value: String,
+    },
+    /// Several lines, such as a grid, printed as a block beneath the part.
+    Grid(Vec<String>),
 }
 
-impl ColoredOutput {
-    pub fn value(&self) -> &str {
-        &self.value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    /// Intermediate values that lead up to the final one, such as `1, 2 => 3`.
+    Sequence,
+}
+
+impl Operator {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+            Operator::Sequence => ",",
+        }
+    }
+}
+
+/// How a span of a rendered answer is styled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Plain,
+    Operand,
+    Value,
+    Operator,
+}
+
+impl Style {
+    fn apply(self, text: &str) -> ColoredString {
+        match self {
+            Style::Plain => text.normal(),
+            Style::Operand => text.white(),
+            Style::Value => text.white().bold(),
+            Style::Operator => text.bright_magenta(),
+        }
+    }
+}
+
+impl Answer {
+    /// The final value, without any of the operands leading up to it.
+    pub fn value(&self) -> Cow<'_, str> {
+        match self {
+            Answer::Scalar(value) | Answer::Text(value) | Answer::Expression { value, .. } => {
+                Cow::Borrowed(value)
+            }
+            Answer::Grid(lines) => Cow::Owned(lines.join("\n")),
+        }
+    }
+
+    /// The lines of a multi-line answer.
+    pub fn lines(&self) -> Option<&[String]> {
+        match self {
+            Answer::Grid(lines) => Some(lines),
+            _ => None,
+        }
+    }
+
+    /// Whether the answer is the expected one.
+    pub fn matches(&self, expected: &str) -> bool {
+        self.value() == expected
+    }
+
+    fn spans(&self) -> Vec<(Style, &str)> {
+        let mut spans = Vec::new();
+        match self {
+            Answer::Scalar(value) | Answer::Text(value) => spans.push((Style::Value, &**value)),
+            Answer::Expression {
+                operator: Operator::Sequence,
+                operands,
+                value,
+            } => {
+                for (i, operand) in operands.iter().enumerate() {
+                    if i != 0 {
+                        spans.extend([(Style::Operator, ","), (Style::Plain, " ")]);
+                    }
+                    spans.push((Style::Operand, operand));
+                }
+                if !operands.is_empty() {
+                    spans.extend([
+                        (Style::Plain, " "),
+                        (Style::Operator, "=>"),
+                        (Style::Plain, " "),
+                    ]);
+                }
+                spans.push((Style::Value, value));
+            }
+            Answer::Expression {
+                operator,
+                operands,
+                value,
+            } => {
+                for (i, operand) in operands.iter().enumerate() {
+                    if i != 0 {
+                        spans.extend([(Style::Operator, operator.symbol()), (Style::Plain, " ")]);
+                    }
+                    spans.extend([(Style::Operand, &**operand), (Style::Plain, " ")]);
+                }
+                if !operands.is_empty() {
+                    spans.extend([(Style::Operator, "="), (Style::Plain, " ")]);
+                }
+                spans.push((Style::Value, value));
+            }
+            Answer::Grid(lines) => {
+                for (i, line) in lines.iter().enumerate() {
+                    if i != 0 {
+                        spans.push((Style::Plain, "\n"));
+                    }
+                    spans.push((Style::Value, line));
+                }
+            }
+        }
+        spans
+    }
+
+    pub fn render(&self, styled: bool) -> String {
+        self.spans()
+            .into_iter()
+            .map(|(style, text)| match styled {
+                true => style.apply(text).to_string(),
+                false => text.to_owned(),
+            })
+            .collect()
+    }
+    /// The answer as it's printed to the terminal, styled unless colors are
+    /// disabled.
+    pub fn styled(&self) -> String {
+        self.render(colored::control::SHOULD_COLORIZE.should_colorize())
     }
-    pub fn control_count(&self) -> usize {
-        self.control_count
+    /// The answer without any styling applied.
+    pub fn plain(&self) -> String {
+        self.render(false)
+    }
+    /// Number of columns that the answer takes up when printed.
+    pub fn width(&self) -> usize {
+        self.spans()
+            .into_iter()
+            .map(|(_, text)| display_width(text))
+            .sum()
     }
 }
 
-impl fmt::Display for ColoredOutput {
+impl fmt::Display for Answer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <String as fmt::Display>::fmt(&self.value, f)
+        f.write_str(&self.styled())
     }
 }
 
-auto trait NotIntoColorOutput {}
-impl !NotIntoColorOutput for ColoredOutput {}
-impl<T, A: std::alloc::Allocator> !NotIntoColorOutput for Vec<T, A> {}
-impl !NotIntoColorOutput for &'static AStr {}
+auto trait NotIntoAnswer {}
+impl !NotIntoAnswer for Answer {}
+impl<T, A: std::alloc::Allocator> !NotIntoAnswer for Vec<T, A> {}
+impl !NotIntoAnswer for &'static AStr {}
+impl !NotIntoAnswer for &str {}
 
-impl<T: fmt::Display + NotIntoColorOutput> From<T> for ColoredOutput {
+impl<T: fmt::Display + NotIntoAnswer> From<T> for Answer {
     fn from(value: T) -> Self {
-        value.to_string().into()
+        Answer::Scalar(value.to_string())
     }
 }
 
-impl From<String> for ColoredOutput {
+impl From<String> for Answer {
     fn from(value: String) -> Self {
-        let before_style_len = value.len();
-        let value = value.white().bold().to_string();
-        let control_count = value.len() - before_style_len;
-        ColoredOutput {
-            value,
-            control_count,
+        match value.contains('\n') {
+            true => GridOutput(value).into(),
+            false => Answer::Text(value),
         }
     }
 }
 
-impl From<AString> for ColoredOutput {
+impl<'s> From<&'s str> for Answer {
+    fn from(value: &'s str) -> Self {
+        value.to_owned().into()
+    }
+}
+
+impl From<AString> for Answer {
     fn from(s: AString) -> Self {
         s.as_slice().into()
     }
 }
 
-impl<'s> From<&'s AStr> for ColoredOutput {
+impl<'s> From<&'s AStr> for Answer {
     fn from(s: &'s AStr) -> Self {
-        let value = String::from_utf8_lossy(s);
-
-        let before_style_len = value.len();
-        let value = value.white().bold().to_string();
-        let control_count = value.len() - before_style_len;
-        ColoredOutput {
-            value,
-            control_count,
-        }
+        String::from_utf8_lossy(s).into_owned().into()
+    }
+}
+
+/// A multi-line answer, such as the output of [`VecGrid::stringify`].
+///
+/// [`VecGrid::stringify`]: crate::grid::VecGrid::stringify
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridOutput<T>(pub T);
+
+impl<T> !NotIntoAnswer for GridOutput<T> {}
+impl<T: fmt::Display> From<GridOutput<T>> for Answer {
+    fn from(input: GridOutput<T>) -> Self {
+        let lines = input.0.to_string().lines().map(str::to_owned).collect();
+        Answer::Grid(lines)
+    }
+}
+
+impl !NotIntoAnswer for BitGrid {}
+impl From<BitGrid> for Answer {
+    fn from(grid: BitGrid) -> Self {
+        GridOutput(grid).into()
     }
 }
 
 macro_rules! impl_binary_op_output {
-    ($symbol:literal, $struct_name:ident, $trait_name:ident, $trait_fn:ident, $identity_trait:ident, $identity_fn:ident) => {
+    ($operator:ident, $struct_name:ident, $trait_name:ident, $trait_fn:ident, $identity_trait:ident, $identity_fn:ident) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         pub struct $struct_name<T>(pub T);
 
-        impl<T> !NotIntoColorOutput for $struct_name<T> {}
-        impl<T, I> From<$struct_name<T>> for ColoredOutput
+        impl<T> !NotIntoAnswer for $struct_name<T> {}
+        impl<T, I> From<$struct_name<T>> for Answer
         where
             T: IntoIterator<Item = I>,
             I: fmt::Display + std::ops::$trait_name<Output = I> + num::$identity_trait,
         {
             fn from(input: $struct_name<T>) -> Self {
-                let mut value = String::new();
-                let mut str_len = 0;
+                let mut operands = Vec::new();
                 let mut acc: I = num::$identity_fn();
-                for (i, v) in input.0.into_iter().enumerate() {
-                    let s = v.to_string();
-                    if i != 0 {
-                        _ = write!(value, "{} ", $symbol.bright_magenta());
-                        str_len += 2;
-                    }
-                    _ = write!(value, "{} ", s.white());
+                for v in input.0 {
+                    operands.push(v.to_string());
                     acc = acc.$trait_fn(v);
-                    str_len += s.len() + 1;
                 }
-
-                if str_len != 0 {
-                    _ = write!(value, "{} ", "=".bright_magenta());
-                    str_len += 2;
-                }
-
-                let s = acc.to_string();
-                _ = write!(value, "{}", s.white().bold());
-                str_len += s.len();
-                let control_count = value.len() - str_len;
-                ColoredOutput {
-                    value,
-                    control_count,
+                Answer::Expression {
+                    operator: Operator::$operator,
+                    operands,
+                    value: acc.to_string(),
                 }
             }
         }
     };
 }
 
-impl_binary_op_output!("+", AddOutput, Add, add, Zero, zero);
-impl_binary_op_output!("-", SubOutput, Sub, sub, Zero, zero);
-impl_binary_op_output!("*", MulOutput, Mul, mul, One, one);
-impl_binary_op_output!("/", DivOutput, Div, div, One, one);
+impl_binary_op_output!(Add, AddOutput, Add, add, Zero, zero);
+impl_binary_op_output!(Sub, SubOutput, Sub, sub, Zero, zero);
+impl_binary_op_output!(Mul, MulOutput, Mul, mul, One, one);
+impl_binary_op_output!(Div, DivOutput, Div, div, One, one);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CombiOutput<T>(pub T);
 
-impl<T> !NotIntoColorOutput for CombiOutput<T> {}
-impl<T, I> From<CombiOutput<T>> for ColoredOutput
+impl<T> !NotIntoAnswer for CombiOutput<T> {}
+impl<T, I> From<CombiOutput<T>> for Answer
 where
     T: IntoIterator<Item = I>,
     I: fmt::Display,
 {
     fn from(input: CombiOutput<T>) -> Self {
-        let items = input.0.into_iter().collect::<Vec<_>>();
-        if items.is_empty() {
-            let value = "empty".italic().dimmed().to_string();
-            let control_count = value.len() - 5;
-            return ColoredOutput {
+        let mut operands = input
+            .0
+            .into_iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        match operands.pop() {
+            Some(value) => Answer::Expression {
+                operator: Operator::Sequence,
+                operands,
                 value,
-                control_count,
-            };
-        }
-
-        let mut value = String::new();
-        let mut str_len = 0;
-        for (i, v) in items[..items.len() - 1].iter().enumerate() {
-            let s = v.to_string();
-            if i != 0 {
-                _ = write!(value, "{} ", ",".bright_magenta());
-                str_len += 2;
-            }
-            _ = write!(value, "{}", s.white());
-            str_len += s.len();
+            },
+            None => Answer::Text("empty".to_owned()),
         }
+    }
+}
 
-        if items.len() != 1 {
-            _ = write!(value, " {} ", "=>".bright_magenta());
-            str_len += 4;
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
 
-        let s = items.last().unwrap().to_string();
-        _ = write!(value, "{}", s.white().bold());
-        str_len += s.len();
+    #[test]
+    fn render() {
+        let answer = Answer::from(MulOutput([3, 4]));
+        assert_eq!("3 * 4 = 12", answer.plain());
+        assert_eq!(10, answer.width());
+        assert_eq!("12", answer.value());
+        assert!(answer.matches("12") && !answer.matches("3 * 4 = 12"));
+        let answer = Answer::from(CombiOutput([1, 2, 3]));
+        assert_eq!("1, 2 => 3", answer.plain());
+        assert_eq!("3", answer.value());
+        assert_eq!(
+            Answer::Text("empty".to_owned()),
+            Answer::from(CombiOutput::<[u8; 0]>([]))
+        );
+        assert_eq!(Answer::Scalar("5".to_owned()), Answer::from(5));
 
-        let control_count = value.len() - str_len;
-        ColoredOutput {
-            value,
-            control_count,
-        }
+        let answer = Answer::from(BitGrid::new((3, 2), true));
+        assert_eq!(
+            Some(&["###".to_owned(), "###".to_owned()][..]),
+            answer.lines()
+        );
+        assert_eq!("###\n###", answer.value());
+        assert_eq!(answer, Answer::from("###\n###".to_owned()));
     }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use std::cmp::Ordering;
 
 pub trait ParserCombiExt<'s>: Sized + Parser<'s> {
     /// Evaluates two parsers sequentially, and returns a tuple of their outputs
@@ -82,7 +83,20 @@ impl<'s, P1: Parser<'s>, P2: Parser<'s, Output = P1::Output>> Parser<'s> for Or<
     type Output = P1::Output;
 
     fn parse(&self, input: &'s [u8]) -> ParseResult<'s, Self::Output> {
-        self.0.parse(input).or_else(|_| self.1.parse(input))
+        let (e0, remainder0) = match self.0.parse(input) {
+            Err(e) => e,
+            ok => return ok,
+        };
+        let (e1, remainder1) = match self.1.parse(input) {
+            Err(e) => e,
+            ok => return ok,
+        };
+        // The alternative that got furthest is the likeliest to be intended.
+        Err(match remainder0.len().cmp(&remainder1.len()) {
+            Ordering::Less => (e0, remainder0),
+            Ordering::Greater => (e1, remainder1),
+            Ordering::Equal => (e0.or(e1), remainder1),
+        })
     }
 }
 
@@ -146,3 +160,41 @@ impl<'s, P: Parser<'s>, F: Fn(&P::Output) -> bool> Parser<'s> for Filter<P, F> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn or_expected() {
+        let color = token(b"red").or(token(b"green")).or(token(b"blue"));
+        let (e, _) = color.parse(b"grean").unwrap_err();
+        assert_eq!(r#"expected one of "red", "green", "blue""#, e.to_string());
+
+        let value = token(b'x')
+            .map(|_| 0)
+            .or(number::<u32>())
+            .or(token(b'x').map(|_| 1));
+        let (e, _) = value.parse(b"-").unwrap_err();
+        assert_eq!(r#"expected one of "x", a digit"#, e.to_string());
+
+        // Errors further into the input win over ones at the start.
+        let pair = token(b"ab").and(token(b'c')).map(|_| ()).or(token(b"ad"));
+        assert_eq!(
+            Err((
+                ParseError::Expected(Expected::Token(b"c".to_vec())),
+                &b"d"[..]
+            )),
+            pair.parse(b"abd")
+        );
+        let letter = pattern!(b'a'..=b'z').or(digit());
+        let (e, _) = letter.parse(b"-").unwrap_err();
+        assert_eq!("expected one of b'a'..=b'z', a digit", e.to_string());
+
+        // At the end of the input, every alternative is still reported.
+        let (e, _) = token(b'x').map(|_| 0).or(digit()).parse(b"").unwrap_err();
+        assert_eq!(r#"expected one of "x", a digit"#, e.to_string());
+        let (e, _) = digit().or(token(b'x').map(|_| 0)).parse(b"").unwrap_err();
+        assert_eq!(r#"expected one of a digit, "x""#, e.to_string());
+    }
+}
@@ -10,9 +10,8 @@ impl<'s> Parser<'s> for Digit {
 
     fn parse(&self, input: &'s [u8]) -> ParseResult<'s, Self::Output> {
         match input.first().cloned() {
-            None => Err((ParseError::EmptyInput, input)),
             Some(d @ b'0'..=b'9') => Ok((d - b'0', &input[1..])),
-            Some(_) => Err((ParseError::ExpectedDigit, input)),
+            _ => Err((ParseError::Expected(Expected::Digit), input)),
         }
     }
 }
@@ -25,9 +24,11 @@ pub macro pattern($p:pat) {{
 
         fn parse(&self, input: &'s [u8]) -> $crate::parsers::ParseResult<'s, Self::Output> {
             match input.first().cloned() {
-                None => Err((ParseError::EmptyInput, input)),
                 Some(v @ $p) => Ok((v, &input[1..])),
-                Some(_) => Err((ParseError::UnexpectedChar, input)),
+                _ => Err((
+                    ParseError::Expected(Expected::Pattern(stringify!($p))),
+                    input,
+                )),
             }
         }
     }
@@ -44,24 +45,24 @@ pub struct Token<T> {
 impl<'s> Parser<'s> for Token<u8> {
     type Output = ();
     fn parse(&self, input: &'s [u8]) -> ParseResult<'s, ()> {
-        if let Some(&c) = input.first() {
-            if c == self.value {
-                return Ok(((), &input[1..]));
-            }
+        if let Some(&c) = input.first()
+            && c == self.value
+        {
+            return Ok(((), &input[1..]));
         }
-        Err((ParseError::TokenDoesNotMatch, input))
+        Err((expected_token(&[self.value]), input))
     }
 }
 
 impl<'s, T: 's + Clone> Parser<'s> for Token<(u8, T)> {
     type Output = T;
     fn parse(&self, input: &'s [u8]) -> ParseResult<'s, T> {
-        if let Some(&c) = input.first() {
-            if c == self.value.0 {
-                return Ok((self.value.1.clone(), &input[1..]));
-            }
+        if let Some(&c) = input.first()
+            && c == self.value.0
+        {
+            return Ok((self.value.1.clone(), &input[1..]));
         }
-        Err((ParseError::TokenDoesNotMatch, input))
+        Err((expected_token(&[self.value.0]), input))
     }
 }
 
@@ -71,7 +72,7 @@ impl<'s, 't: 's> Parser<'s> for Token<&'t [u8]> {
         if input.starts_with(self.value) {
             Ok(((), &input[self.value.len()..]))
         } else {
-            Err((ParseError::TokenDoesNotMatch, input))
+            Err((expected_token(self.value), input))
         }
     }
 }
@@ -82,7 +83,7 @@ impl<'s, 't: 's, T: 's + Clone> Parser<'s> for Token<(&'t [u8], T)> {
         if input.starts_with(self.value.0) {
             Ok((self.value.1.clone(), &input[self.value.0.len()..]))
         } else {
-            Err((ParseError::TokenDoesNotMatch, input))
+            Err((expected_token(self.value.0), input))
         }
     }
 }
@@ -93,7 +94,7 @@ impl<'s, 't: 's, const N: usize> Parser<'s> for Token<&'t [u8; N]> {
         if input.starts_with(self.value) {
             Ok(((), &input[self.value.len()..]))
         } else {
-            Err((ParseError::TokenDoesNotMatch, input))
+            Err((expected_token(self.value), input))
         }
     }
 }
@@ -104,11 +105,15 @@ impl<'s, 't: 's, T: 's + Clone, const N: usize> Parser<'s> for Token<(&'t [u8; N
         if input.starts_with(self.value.0) {
             Ok((self.value.1.clone(), &input[self.value.0.len()..]))
         } else {
-            Err((ParseError::TokenDoesNotMatch, input))
+            Err((expected_token(self.value.0), input))
         }
     }
 }
 
+fn expected_token(token: &[u8]) -> ParseError {
+    ParseError::Expected(Expected::Token(token.to_vec()))
+}
+
 pub fn token<T>(token: T) -> Token<T> {
     Token { value: token }
 }
@@ -156,8 +161,8 @@ mod test {
         assert_eq!( Ok((10,                        &b"abc" [..])), number::<u8>().parse(b"10abc"));
         assert_eq!(Err((ParseError::Overflow,      &b"300" [..])), number::<u8>().parse(b"300"  ));
         assert_eq!(Err((ParseError::Overflow,      &b"256a"[..])), number::<u8>().parse(b"256a" ));
-        assert_eq!(Err((ParseError::EmptyInput,    &b""    [..])), number::<u8>().parse(b""     ));
-        assert_eq!(Err((ParseError::ExpectedDigit, &b"-1"  [..])), number::<u8>().parse(b"-1"   ));
+        assert_eq!(Err((ParseError::Expected(Expected::Digit), &b""[..])), number::<u8>().parse(b""));
+        assert_eq!(Err((ParseError::Expected(Expected::Digit), &b"-1"[..])), number::<u8>().parse(b"-1"));
     }
 
     #[test]
@@ -170,6 +175,6 @@ mod test {
         assert_eq!( Ok((10,                        &b"abc" [..])), number::<i8>().parse(b"10abc"));
         assert_eq!(Err((ParseError::Overflow,      &b"+128"[..])), number::<i8>().parse(b"+128" ));
         assert_eq!(Err((ParseError::Overflow,      &b"-129"[..])), number::<i8>().parse(b"-129" ));
-        assert_eq!(Err((ParseError::EmptyInput,    &b""    [..])), number::<i8>().parse(b""     ));
+        assert_eq!(Err((ParseError::Expected(Expected::Digit), &b""[..])), number::<i8>().parse(b""));
     }
 }
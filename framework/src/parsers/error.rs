@@ -1,17 +1,22 @@
+use crate::util::display_width;
+use itertools::Itertools;
+use std::fmt;
 use thiserror::Error;
 
 pub type ParseResult<'s, T> = Result<(T, &'s [u8]), (ParseError, &'s [u8])>;
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ParseError {
-    #[error("input not fully consumed, remainder: {0:?}")]
-    InputNotConsumed(String),
-    #[error("{0}, remainder: {1:?}")]
-    WithRemainder(Box<ParseError>, String),
+    #[error("input not fully consumed at {0}")]
+    InputNotConsumed(Location),
+    #[error("{0} at {1}")]
+    At(Box<ParseError>, Location),
     #[error("empty input")]
     EmptyInput,
-    #[error("expected a digit")]
-    ExpectedDigit,
+    #[error("expected {0}")]
+    Expected(Expected),
+    #[error("expected one of {}", .0.iter().format(", "))]
+    ExpectedOneOf(Vec<Expected>),
     #[error("overflow")]
     Overflow,
     #[error("token does not match")]
@@ -27,3 +32,151 @@ pub enum ParseError {
     #[error("{0}")]
     Custom(&'static str),
 }
+
+impl ParseError {
+    /// Where in the input parsing failed, if known.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            ParseError::InputNotConsumed(location) | ParseError::At(_, location) => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Combines the errors of two alternatives that failed at the same
+    /// position, so that everything they expected is reported.
+    pub fn or(self, other: ParseError) -> ParseError {
+        let (mut expected, other) = match (self, other) {
+            (ParseError::Expected(a), ParseError::Expected(b)) => (vec![a], vec![b]),
+            (ParseError::Expected(a), ParseError::ExpectedOneOf(b)) => (vec![a], b),
+            (ParseError::ExpectedOneOf(a), ParseError::Expected(b)) => (a, vec![b]),
+            (ParseError::ExpectedOneOf(a), ParseError::ExpectedOneOf(b)) => (a, b),
+            (_, other) => return other,
+        };
+        for e in other {
+            if !expected.contains(&e) {
+                expected.push(e);
+            }
+        }
+        match <[_; 1]>::try_from(expected) {
+            Ok([e]) => ParseError::Expected(e),
+            Err(expected) => ParseError::ExpectedOneOf(expected),
+        }
+    }
+}
+
+/// What a parser expected at the position where it failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Token(Vec<u8>),
+    Digit,
+    /// The source of the pattern passed to `pattern!`.
+    Pattern(&'static str),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Token(token) => write!(f, "{:?}", String::from_utf8_lossy(token)),
+            Expected::Digit => f.write_str("a digit"),
+            Expected::Pattern(pattern) => f.write_str(pattern),
+        }
+    }
+}
+
+/// Longest part of a line shown in a snippet.
+const SNIPPET_WIDTH: usize = 60;
+
+/// A position in the input, with the surrounding part of its line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in bytes.
+    pub column: usize,
+    text: String,
+    /// Position of the caret in `text`, in terminal columns.
+    caret: usize,
+}
+
+impl Location {
+    pub fn new(input: &[u8], offset: usize) -> Location {
+        let start = input[..offset]
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |i| i + 1);
+        let end = input[offset..]
+            .iter()
+            .position(|&c| c == b'\n')
+            .map_or(input.len(), |i| offset + i);
+        let line = input[..start].iter().filter(|&&c| c == b'\n').count() + 1;
+
+        // Long lines are cut short around the offset, without splitting
+        // UTF-8 sequences.
+        let is_continuation = |i: usize| input.get(i).is_some_and(|&c| c & 0xC0 == 0x80);
+        let mut from = offset.saturating_sub(SNIPPET_WIDTH / 2).max(start);
+        while from > start && is_continuation(from) {
+            from -= 1;
+        }
+        let mut to = (from + SNIPPET_WIDTH).min(end);
+        while to < end && is_continuation(to) {
+            to += 1;
+        }
+        let lossy = |bytes| String::from_utf8_lossy(bytes).replace(char::is_control, " ");
+        let mut text = lossy(&input[from..to]);
+        let mut caret = display_width(&lossy(&input[from..offset]));
+        if from > start {
+            text.insert_str(0, "...");
+            caret += 3;
+        }
+        if to < end {
+            text.push_str("...");
+        }
+
+        Location {
+            line,
+            column: offset - start + 1,
+            text,
+            caret,
+        }
+    }
+
+    /// The line, and a caret pointing at the column beneath it, both prefixed
+    /// with the line number.
+    pub fn snippet(&self) -> (String, String) {
+        let gutter = self.line.to_string();
+        let source = format!("{gutter} | {}", self.text);
+        let caret = format!("{:w$} | {:c$}^", "", "", w = gutter.len(), c = self.caret);
+        (source, caret)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let input = b"Game 1: 3 blue\nGame 2: 4 grean, 1 red\n";
+        let location = Location::new(input, 25);
+        assert_eq!((2, 11), (location.line, location.column));
+        let (source, caret) = location.snippet();
+        assert_eq!("2 | Game 2: 4 grean, 1 red", source);
+        assert_eq!("  |           ^", caret);
+
+        let line = [b'.'; 200];
+        let (source, caret) = Location::new(&line, 70).snippet();
+        assert_eq!(format!("1 | ...{}...", ".".repeat(60)), source);
+        assert_eq!(format!("  | {}^", " ".repeat(33)), caret);
+
+        let line = format!("{}x", "😀".repeat(20));
+        let (source, caret) = Location::new(line.as_bytes(), 80).snippet();
+        assert_eq!(format!("1 | ...{}x", "😀".repeat(8)), source);
+        assert_eq!(format!("  | {}^", " ".repeat(19)), caret);
+    }
+}
@@ -8,7 +8,7 @@ mod util;
 
 pub use combi::ParserCombiExt;
 pub use common::{any, digit, constant, pattern, token};
-pub use error::{ParseError, ParseResult};
+pub use error::{Expected, Location, ParseError, ParseResult};
 pub use grid::grid;
 pub use multi::{take_n, take_while, ParserMultiExt};
 pub use numbers::number;
@@ -25,15 +25,12 @@ pub trait Execute<'s, T> {
 
 impl<'s, P: Parser<'s, Output = T>, T> Execute<'s, T> for P {
     fn execute(&self, input: &'s [u8]) -> crate::result::Result<T> {
+        // Parsers only ever return suffixes of the input they're given.
+        let location = |remainder: &[u8]| Location::new(input, input.len() - remainder.len());
         Err(match self.parse(input) {
             Ok((x, [] | [b'\n'])) => return Ok(x),
-            Ok((_, remainder)) => {
-                ParseError::InputNotConsumed(String::from_utf8_lossy(remainder).into_owned())
-            }
-            Err((e, remainder)) => ParseError::WithRemainder(
-                Box::new(e),
-                String::from_utf8_lossy(remainder).into_owned(),
-            ),
+            Ok((_, remainder)) => ParseError::InputNotConsumed(location(remainder)),
+            Err((e, remainder)) => ParseError::At(Box::new(e), location(remainder)),
         }
         .into())
     }
@@ -9,10 +9,10 @@ macro_rules! impl_uint_parsing {
             type Output = $kind;
 
             fn parse(&self, input: &'s [u8]) -> ParseResult<'s, Self::Output> {
-                let first_char = *input.first().ok_or((ParseError::EmptyInput, input))?;
-                if !matches!(first_char, b'0'..=b'9') {
-                    return Err((ParseError::ExpectedDigit, input));
-                }
+                let first_char = match input.first() {
+                    Some(&c @ b'0'..=b'9') => c,
+                    _ => return Err((ParseError::Expected(Expected::Digit), input)),
+                };
 
                 let mut remainder = &input[1..];
 
@@ -1,6 +1,7 @@
 pub use crate::astr::{AStr, AString};
+pub use crate::cancel::Checkpoint;
 pub use crate::cbuffer::{CBuffer, CBufferMutator};
-pub use crate::error::Error;
+pub use crate::error::{Context, Error};
 pub use crate::graph;
 pub use crate::grid::{BitGrid, VecGrid};
 pub use crate::if_test;
@@ -12,7 +13,7 @@ pub use crate::ocr;
 pub use crate::offsets::{Neighbor, Neighbors, NeighborsAlong, Offset};
 pub use crate::outputs::*;
 pub use crate::parsers;
-pub use crate::result::Result;
+pub use crate::result::{Result, ResultExt};
 pub use crate::tests;
 pub use crate::util::{self, OrdExt, SliceExt};
 pub use crate::vecs::*;
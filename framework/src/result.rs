@@ -1,4 +1,6 @@
-pub type Result<T> = std::result::Result<T, crate::error::Error>;
+use crate::error::{Context, Error};
+
+pub type Result<T> = std::result::Result<T, Error>;
 
 pub auto trait IsNotResult {}
 impl<T, E> !IsNotResult for std::result::Result<T, E> {}
@@ -20,3 +22,22 @@ impl<T> IntoResult for Result<T> {
         self
     }
 }
+
+/// Adds context to errors, such as the day, or where in the input they
+/// happened.
+pub trait ResultExt<T> {
+    fn context(self, context: impl Into<Context>) -> Result<T>;
+    /// Like `context`, but only builds the context on error, such as when
+    /// it's formatted.
+    fn with_context<C: Into<Context>>(self, f: impl FnOnce() -> C) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> ResultExt<T> for std::result::Result<T, E> {
+    fn context(self, context: impl Into<Context>) -> Result<T> {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C: Into<Context>>(self, f: impl FnOnce() -> C) -> Result<T> {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
@@ -0,0 +1,458 @@
+use super::{
+    Day,
+    report::{ColorChoice, Format},
+    stats::{Repeat, parse_duration},
+};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Command {
+    #[default]
+    Run,
+    Bench,
+    Test,
+    Submit,
+    Verify,
+    Encrypt,
+    Watch,
+    List,
+    Help,
+}
+
+impl Command {
+    fn from_name(name: &str) -> Option<Command> {
+        Some(match name {
+            "run" => Command::Run,
+            "bench" => Command::Bench,
+            "test" => Command::Test,
+            "submit" => Command::Submit,
+            "verify" => Command::Verify,
+            "encrypt" => Command::Encrypt,
+            "watch" => Command::Watch,
+            "list" => Command::List,
+            "help" | "-h" | "--help" => Command::Help,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CliError {
+    #[error("unknown command '{0}'")]
+    UnknownCommand(String),
+    #[error("unknown option '{0}'")]
+    UnknownOption(String),
+    #[error("missing value for option '{0}'")]
+    MissingValue(String),
+    #[error("invalid value '{1}' for option '{0}'")]
+    InvalidValue(String, String),
+    #[error("invalid day '{0}'")]
+    InvalidDay(String),
+    #[error("invalid day range '{0}'")]
+    InvalidRange(String),
+    #[error("day {0} is not available")]
+    UnknownDay(u32),
+    #[error("day {0} has no part '{1}'")]
+    UnknownPart(u32, String),
+    #[error("--input requires exactly one day to be selected")]
+    InputRequiresSingleDay,
+    #[error("watch requires exactly one day to be selected")]
+    WatchRequiresSingleDay,
+    #[error("submit requires a single day and part, such as 'submit 5 pt1'")]
+    SubmitRequiresPart,
+    #[error("options '{0}' and '{1}' can't be combined")]
+    ConflictingOptions(&'static str, &'static str),
+}
+
+/// Which parts of a day should be executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartFilter {
+    All,
+    Only(Vec<String>),
+}
+
+impl PartFilter {
+    pub fn includes(&self, part: &str) -> bool {
+        match self {
+            PartFilter::All => true,
+            PartFilter::Only(parts) => parts.iter().any(|p| p == part),
+        }
+    }
+
+    fn merge(&mut self, other: PartFilter) {
+        match (&mut *self, other) {
+            (PartFilter::All, _) => {}
+            (_, PartFilter::All) => *self = PartFilter::All,
+            (PartFilter::Only(parts), PartFilter::Only(other)) => {
+                for part in other {
+                    if !parts.contains(&part) {
+                        parts.push(part);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The days (and parts) selected on the command line. An empty selection
+/// includes everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    entries: Vec<(u32, PartFilter)>,
+}
+
+impl Selection {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the parts to run for a day, or `None` if it isn't selected.
+    pub fn parts(&self, day: u32) -> Option<&PartFilter> {
+        if self.entries.is_empty() {
+            return Some(&PartFilter::All);
+        }
+        self.entries
+            .iter()
+            .find(|(nr, _)| *nr == day)
+            .map(|(_, parts)| parts)
+    }
+
+    fn insert(&mut self, day: u32, parts: PartFilter) {
+        match self.entries.iter_mut().find(|(nr, _)| *nr == day) {
+            Some((_, existing)) => existing.merge(parts),
+            None => self.entries.push((day, parts)),
+        }
+    }
+
+    /// Parses a day specification, such as `5`, `1-5,8`, or `5:pt2`.
+    fn parse_spec(&mut self, spec: &str) -> Result<(), CliError> {
+        for item in spec.split(',') {
+            let (days, part) = match item.split_once(':') {
+                Some((days, part)) => (days, Some(part)),
+                None => (item, None),
+            };
+            let parse_day = |s: &str| {
+                s.parse::<u32>()
+                    .map_err(|_| CliError::InvalidDay(item.to_owned()))
+            };
+            let (first, last) = match days.split_once('-') {
+                Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+                None => (parse_day(days)?, parse_day(days)?),
+            };
+            if first > last {
+                return Err(CliError::InvalidRange(item.to_owned()));
+            }
+            let parts = match part {
+                Some("") => return Err(CliError::InvalidDay(item.to_owned())),
+                Some(part) => PartFilter::Only(vec![part.to_owned()]),
+                None => PartFilter::All,
+            };
+            for day in first..=last {
+                self.insert(day, parts.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cli {
+    pub command: Command,
+    pub selection: Selection,
+    pub format: Format,
+    pub color: ColorChoice,
+    /// Store the answers as the new expected answers.
+    pub record: bool,
+    pub repeat: Repeat,
+    /// Run days concurrently on a thread pool.
+    pub parallel: bool,
+    /// Stop at the first day that fails, rather than running the others.
+    pub fail_fast: bool,
+    /// Cancel the parse function or a part after this much time.
+    pub timeout: Option<Duration>,
+    /// File to use as the input, `-` for stdin.
+    pub input: Option<String>,
+    /// Directory containing the `NN.txt` inputs.
+    pub inputs_dir: Option<String>,
+    /// Profiles to run, with their inputs in `<inputs_dir>/<profile>/`.
+    pub profiles: Vec<String>,
+    /// Run every profile found in the inputs directory.
+    pub all_profiles: bool,
+    /// Download modified inputs again when verifying them.
+    pub refetch: bool,
+}
+
+impl Cli {
+    pub fn parse<S: AsRef<str>>(args: &[S]) -> Result<Cli, CliError> {
+        let mut cli = Cli::default();
+        let mut args = args.iter().map(AsRef::as_ref);
+        let mut is_first = true;
+        while let Some(arg) = args.next() {
+            if let Some(command) = Command::from_name(arg)
+                && (is_first || command == Command::Help)
+            {
+                is_first = false;
+                cli.command = command;
+                continue;
+            }
+            if arg.starts_with("--") {
+                let (name, value) = match arg.split_once('=') {
+                    Some((name, value)) => (name, Some(value)),
+                    None => (arg, None),
+                };
+                let mut value = || {
+                    value
+                        .or_else(|| args.next())
+                        .ok_or_else(|| CliError::MissingValue(name.to_owned()))
+                };
+                let invalid =
+                    |value: &str| CliError::InvalidValue(name.to_owned(), value.to_owned());
+                match name {
+                    "--record" => cli.record = true,
+                    "--parallel" => cli.parallel = true,
+                    "--fail-fast" => cli.fail_fast = true,
+                    "--refetch" => cli.refetch = true,
+                    "--input" => cli.input = Some(value()?.to_owned()),
+                    "--inputs-dir" => cli.inputs_dir = Some(value()?.to_owned()),
+                    "--profile" => {
+                        let value = value()?;
+                        for profile in value.split(',') {
+                            if profile.is_empty() || profile.contains(['/', '\\']) {
+                                return Err(invalid(value));
+                            }
+                            cli.profiles.push(profile.to_owned());
+                        }
+                    }
+                    "--all-profiles" => cli.all_profiles = true,
+                    "--repeat" => {
+                        let value = value()?;
+                        let count = value.parse().ok().filter(|&count| count > 0);
+                        cli.repeat.count = Some(count.ok_or_else(|| invalid(value))?);
+                    }
+                    "--min-time" => {
+                        let value = value()?;
+                        let min_time = parse_duration(value).ok_or_else(|| invalid(value))?;
+                        cli.repeat.min_time = Some(min_time);
+                    }
+                    "--timeout" => {
+                        let value = value()?;
+                        let timeout = parse_duration(value).ok_or_else(|| invalid(value))?;
+                        cli.timeout = Some(timeout);
+                    }
+                    "--format" => {
+                        let value = value()?;
+                        cli.format = Format::from_name(value).ok_or_else(|| invalid(value))?;
+                    }
+                    "--color" => {
+                        let value = value()?;
+                        cli.color = ColorChoice::from_name(value).ok_or_else(|| invalid(value))?;
+                    }
+                    _ => return Err(CliError::UnknownOption(arg.to_owned())),
+                }
+                continue;
+            }
+            if arg.starts_with('-') {
+                return Err(CliError::UnknownOption(arg.to_owned()));
+            }
+            // `submit 5 pt1` is short for `submit 5:pt1`.
+            if cli.command == Command::Submit
+                && !arg.starts_with(|c: char| c.is_ascii_digit())
+                && let [(_, parts @ PartFilter::All)] = &mut cli.selection.entries[..]
+            {
+                *parts = PartFilter::Only(vec![arg.to_owned()]);
+                continue;
+            }
+            if is_first && !arg.starts_with(|c: char| c.is_ascii_digit()) {
+                return Err(CliError::UnknownCommand(arg.to_owned()));
+            }
+            is_first = false;
+            cli.selection.parse_spec(arg)?;
+        }
+        Ok(cli)
+    }
+
+    /// Checks that every selected day and part exists.
+    pub fn validate(&self, days: &[Day]) -> Result<(), CliError> {
+        if self.input.is_some() {
+            if self.selection.entries.len() != 1 {
+                return Err(CliError::InputRequiresSingleDay);
+            }
+            if !self.profiles.is_empty() {
+                return Err(CliError::ConflictingOptions("--input", "--profile"));
+            }
+            if self.all_profiles {
+                return Err(CliError::ConflictingOptions("--input", "--all-profiles"));
+            }
+        }
+        if self.command == Command::Watch {
+            if self.selection.entries.len() != 1 {
+                return Err(CliError::WatchRequiresSingleDay);
+            }
+            if self.input.as_deref() == Some("-") {
+                return Err(CliError::ConflictingOptions("watch", "--input -"));
+            }
+            // A JSON array is only printed once the run ends, which watch
+            // never does, ndjson is printed as it goes.
+            if self.format == Format::Json {
+                return Err(CliError::ConflictingOptions("watch", "--format json"));
+            }
+        }
+        // Answers aren't checked against a single file, which `test` requires.
+        if self.command == Command::Test && self.input.is_some() {
+            return Err(CliError::ConflictingOptions("test", "--input"));
+        }
+        if self.command == Command::Submit {
+            let [(_, PartFilter::Only(parts))] = &self.selection.entries[..] else {
+                return Err(CliError::SubmitRequiresPart);
+            };
+            if parts.len() != 1 {
+                return Err(CliError::SubmitRequiresPart);
+            }
+            if self.input.is_some() {
+                return Err(CliError::ConflictingOptions("submit", "--input"));
+            }
+            if self.inputs_dir.is_some() {
+                return Err(CliError::ConflictingOptions("submit", "--inputs-dir"));
+            }
+            if !self.profiles.is_empty() || self.all_profiles {
+                return Err(CliError::ConflictingOptions("submit", "--profile"));
+            }
+        }
+        if self.all_profiles && !self.profiles.is_empty() {
+            return Err(CliError::ConflictingOptions("--profile", "--all-profiles"));
+        }
+        for (nr, parts) in &self.selection.entries {
+            let day = days
+                .iter()
+                .find(|day| day.number == *nr)
+                .ok_or(CliError::UnknownDay(*nr))?;
+            if let PartFilter::Only(parts) = parts
+                && let Some(part) = parts.iter().find(|p| !day.parts.contains(&p.as_str()))
+            {
+                return Err(CliError::UnknownPart(*nr, part.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether each day is run against several profiles.
+    pub fn is_matrix(&self) -> bool {
+        self.all_profiles || self.profiles.len() > 1
+    }
+}
+
+pub fn usage() -> &'static str {
+    "\
+usage: advent_of_code_2023 [COMMAND] [DAYS...]
+
+commands:
+  run      run the selected days and print their answers (default)
+  bench    run each selected part repeatedly and print timing statistics
+  test     run the selected days, like run, but also fail when an answer
+           can't be checked because none is recorded
+  submit   run a single part, such as 'submit 5 pt1', and submit its answer,
+           unless earlier submissions show that it's wrong
+  verify   check that the inputs weren't modified after they were downloaded
+  encrypt  replace the selected inputs with ones encrypted with the input key
+  watch    run a single day, and run it again whenever its input changes,
+           such as 'watch 5 --input scratch.txt'
+  list     list the available days and their parts
+  help     print this message
+
+options:
+  --format <FORMAT>    output format: text (default), json, or ndjson
+  --color <WHEN>       color the output: auto (default), always, or never,
+                       auto disables colors when not printing to a terminal,
+                       or when NO_COLOR is set
+  --record             store the answers in answers/NN.txt as the expected ones,
+                       or with verify, accept the current inputs as downloaded
+  --refetch            with verify, download modified or missing inputs again
+  --repeat <N>         execute each parse and part function at least N times
+  --min-time <TIME>    repeat each function until TIME (e.g. 500ms) has passed
+  --parallel           run days concurrently, timings will be less accurate
+  --timeout <TIME>     stop parts that take longer than TIME (e.g. 30s), only
+                       works for code that checks for cancellation, such as
+                       the graph searches
+  --fail-fast          stop at the first day that fails, instead of running the
+                       remaining days and reporting the failures at the end
+  --input <PATH>       use PATH as the input of the selected day, - for stdin
+  --inputs-dir <DIR>   read inputs from DIR/NN.txt instead of inputs/NN.txt
+  --profile <NAME>     use the inputs in inputs/NAME/ and answers in answers/NAME/,
+                       several profiles print a matrix of their answers
+  --all-profiles       run every profile found in the inputs directory
+
+days:
+  5        a single day
+  1-5,8    ranges and lists of days
+  5:pt2    a single part of a day
+"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn only(part: &str) -> PartFilter {
+        PartFilter::Only(vec![part.to_owned()])
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn commands() {
+        assert_eq!(Ok(Command::Run), Cli::parse::<&str>(&[]).map(|c| c.command));
+        assert_eq!(Ok(Command::Bench), Cli::parse(&["bench", "5"]).map(|c| c.command));
+        assert_eq!(Ok(Command::Help), Cli::parse(&["5", "--help"]).map(|c| c.command));
+        assert_eq!(Err(CliError::UnknownCommand("bnech".to_owned())), Cli::parse(&["bnech"]));
+        assert_eq!(Err(CliError::UnknownOption("--day".to_owned())), Cli::parse(&["--day", "5"]));
+        assert_eq!(Err(CliError::InvalidDay("list".to_owned())), Cli::parse(&["5", "list"]));
+        let cli = Cli::parse(&["submit", "5", "pt2"]).unwrap();
+        assert_eq!((Command::Submit, Some(&only("pt2"))), (cli.command, cli.selection.parts(5)));
+        assert_eq!(Err(CliError::InvalidDay("pt2".to_owned())), Cli::parse(&["submit", "pt2"]));
+        assert_eq!(Ok(Command::Watch), Cli::parse(&["watch", "5"]).map(|c| c.command));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn options() {
+        assert_eq!(Ok(Format::Json), Cli::parse(&["--format", "json"]).map(|c| c.format));
+        assert_eq!(Ok(Command::Bench), Cli::parse(&["--format", "json", "bench"]).map(|c| c.command));
+        assert_eq!(Ok(Format::Ndjson), Cli::parse(&["5", "--format=ndjson"]).map(|c| c.format));
+        assert_eq!(Err(CliError::MissingValue("--format".to_owned())), Cli::parse(&["--format"]));
+        assert_eq!(Ok(true), Cli::parse(&["test", "--record"]).map(|c| c.record));
+        let repeat = Repeat { count: Some(10), min_time: Some(Duration::from_millis(100)) };
+        assert_eq!(Ok(repeat), Cli::parse(&["--repeat", "10", "--min-time=100ms"]).map(|c| c.repeat));
+        assert_eq!(Ok(Some(Duration::from_secs(30))), Cli::parse(&["--timeout=30s"]).map(|c| c.timeout));
+        assert_eq!(Err(CliError::InvalidValue("--repeat".to_owned(), "x".to_owned())), Cli::parse(&["--repeat", "x"]));
+        assert_eq!(Err(CliError::InvalidValue("--repeat".to_owned(), "0".to_owned())), Cli::parse(&["--repeat", "0"]));
+        assert_eq!(Err(CliError::InvalidValue("--format".to_owned(), "xml".to_owned())), Cli::parse(&["--format=xml"]));
+        assert_eq!(Ok(ColorChoice::Never), Cli::parse(&["--color=never"]).map(|c| c.color));
+        assert_eq!(Ok(Some("-".to_owned())), Cli::parse(&["5", "--input", "-"]).map(|c| c.input));
+        assert_eq!(Ok(Some("alt".to_owned())), Cli::parse(&["--inputs-dir=alt"]).map(|c| c.inputs_dir));
+        let profiles = vec!["alice".to_owned(), "bob".to_owned(), "carol".to_owned()];
+        assert_eq!(Ok(profiles), Cli::parse(&["--profile=alice,bob", "--profile", "carol"]).map(|c| c.profiles));
+        assert_eq!(Err(CliError::InvalidValue("--profile".to_owned(), "../x".to_owned())), Cli::parse(&["--profile", "../x"]));
+        assert_eq!(Ok(true), Cli::parse(&["--all-profiles"]).map(|c| c.is_matrix()));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn selections() {
+        let cli = Cli::parse(&["1-3,8", "5:pt2", "5:pt1", "2:pt1"]).unwrap();
+        assert_eq!(Some(&PartFilter::All), cli.selection.parts(1));
+        assert_eq!(Some(&PartFilter::All), cli.selection.parts(2));
+        assert_eq!(Some(&PartFilter::All), cli.selection.parts(8));
+        assert_eq!(None, cli.selection.parts(4));
+        let pt2_pt1 = PartFilter::Only(vec!["pt2".to_owned(), "pt1".to_owned()]);
+        assert_eq!(Some(&pt2_pt1), cli.selection.parts(5));
+
+        let cli = Cli::parse(&["run", "3-4:pt1"]).unwrap();
+        assert_eq!(Some(&only("pt1")), cli.selection.parts(3));
+        assert_eq!(Some(&only("pt1")), cli.selection.parts(4));
+
+        assert_eq!(Err(CliError::InvalidRange("5-1".to_owned())), Cli::parse(&["5-1"]));
+        assert_eq!(Err(CliError::InvalidDay("1-x".to_owned())), Cli::parse(&["1-x"]));
+        assert_eq!(Err(CliError::InvalidDay("5:".to_owned())), Cli::parse(&["5:"]));
+    }
+}
@@ -0,0 +1,89 @@
+use super::{
+    cli::PartFilter,
+    report::{DayResult, PartResult, Timings},
+    stats::{Repeat, Stats},
+};
+use crate::{
+    cancel::CancellationToken,
+    error::Context,
+    outputs::Answer,
+    result::{Result, ResultExt},
+};
+use std::time::Duration;
+
+/// Drives the execution of a single day, as generated by the `day!` macro.
+/// Decides which parts are run and how they're timed, and collects the
+/// results for rendering.
+#[derive(Debug)]
+pub struct Harness {
+    repeat: Repeat,
+    parts: PartFilter,
+    /// Time after which the parse function, or a part, is cancelled.
+    timeout: Option<Duration>,
+    result: DayResult,
+}
+
+impl Harness {
+    pub(super) fn new(
+        day: u32,
+        repeat: Repeat,
+        parts: PartFilter,
+        timeout: Option<Duration>,
+    ) -> Harness {
+        Harness {
+            repeat,
+            parts,
+            timeout,
+            result: DayResult {
+                day,
+                timings: Timings::default(),
+                parts: Vec::new(),
+                profile: None,
+                warnings: Vec::new(),
+                error: None,
+            },
+        }
+    }
+
+    pub(super) fn finish(mut self, outcome: Result<()>) -> DayResult {
+        let day = self.result.day;
+        self.result.error = outcome.context(Context::Day(day)).err();
+        self.result
+    }
+
+    pub fn includes(&self, part: &str) -> bool {
+        self.parts.includes(part)
+    }
+
+    /// Records how long parsing took, passing on its output. The day is
+    /// added to the error when the day finishes.
+    pub fn record_parse<T>(&mut self, parsed: Result<T>, time: Stats) -> Result<T> {
+        self.result.timings.parse = time;
+        parsed.context(Context::Part("parse"))
+    }
+
+    pub fn record_part(&mut self, name: &'static str, answer: Result<Answer>, time: Stats) {
+        let day = self.result.day;
+        let answer = answer
+            .context(Context::Part(name))
+            .context(Context::Day(day));
+        self.result.timings.parts.push((name, time));
+        self.result.parts.push(PartResult {
+            name,
+            answer,
+            expected: None,
+        });
+    }
+
+    /// Runs `f`, returning its output and how long it took. When repeating,
+    /// the output of the last execution is returned. With a timeout, each
+    /// execution of `f` is cancelled once it runs out of time, which only
+    /// affects code that checks for cancellation, see [`crate::cancel`].
+    pub fn measure<T>(&mut self, mut f: impl FnMut() -> T) -> (T, Stats) {
+        let timeout = self.timeout;
+        self.repeat.measure(|| match timeout {
+            Some(timeout) => CancellationToken::with_timeout(timeout).run(&mut f),
+            None => f(),
+        })
+    }
+}
@@ -0,0 +1,870 @@
+mod cli;
+mod harness;
+mod panics;
+mod report;
+mod stats;
+mod terminal;
+
+pub use self::{
+    cli::{Cli, CliError, Command, PartFilter, Selection},
+    harness::Harness,
+    panics::catch_panic,
+    report::{ColorChoice, DayResult, Format, PartResult, Report, Timings},
+    stats::{Repeat, Stats},
+};
+pub use crate::{
+    answers::{Answers, Verdict},
+    inputs::{DirectorySource, FileSource, InputSource, Inputs, Integrity, StdinSource},
+    outputs::Answer,
+    result::{IntoResult, Result},
+    util::display_width,
+};
+use crate::{
+    config::Config,
+    error::{Context, Error},
+    inputs,
+    result::ResultExt,
+    submissions::{Outcome, Submissions},
+};
+use colored::ColoredString;
+pub use colored::Colorize;
+use rayon::prelude::*;
+pub use std::io::Write;
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::Duration,
+};
+
+/// Default amount of time spent repeating a single function in `bench`.
+const BENCH_MIN_TIME: Duration = Duration::from_millis(250);
+/// How often `watch` checks whether files changed.
+const WATCH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A day as registered by `main!`, constructed by `day!`.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub parts: &'static [&'static str],
+    pub execute: fn(&Inputs, &mut Harness) -> Result<()>,
+}
+
+pub fn main(days: &[Day]) -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let cli = match Cli::parse(&args).and_then(|cli| cli.validate(days).map(|_| cli)) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("{}: {e}", "error".bright_red().bold());
+            eprintln!("run with 'help' for usage");
+            return ExitCode::from(2);
+        }
+    };
+
+    cli.color.apply();
+
+    match cli.command {
+        Command::Help => {
+            print!("{}", cli::usage());
+            ExitCode::SUCCESS
+        }
+        Command::List => {
+            list(days, &cli.selection);
+            ExitCode::SUCCESS
+        }
+        Command::Run | Command::Bench | Command::Test => execute(days, &cli),
+        Command::Submit => submit(days, &cli),
+        Command::Verify => verify(days, &cli),
+        Command::Encrypt => encrypt(days, &cli),
+        Command::Watch => watch(days, &cli),
+    }
+}
+
+fn list(days: &[Day], selection: &Selection) {
+    for day in days {
+        let Some(parts) = selection.parts(day.number) else {
+            continue;
+        };
+        print!(
+            "{} {}",
+            "Day".bright_blue(),
+            format!("{:>2}", day.number).bright_red().bold()
+        );
+        for part in day.parts.iter().filter(|part| parts.includes(part)) {
+            print!(" {} {}", "::".magenta(), part.bright_yellow());
+        }
+        println!();
+    }
+}
+
+/// Checks the selected inputs against the manifest of downloaded inputs, and
+/// if requested, records their hashes, or downloads them again.
+fn verify(days: &[Day], cli: &Cli) -> ExitCode {
+    let profiles = match Config::load().and_then(|config| profiles(cli, &config)) {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            print_error(&e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut modified = 0;
+    let mut failures = 0;
+    for day in days {
+        if cli.selection.parts(day.number).is_none() {
+            continue;
+        }
+        for profile in &profiles {
+            let inputs = &profile.inputs;
+            let status = inputs.verify(day.number).and_then(|integrity| {
+                Ok(match integrity {
+                    _ if cli.record && integrity != Integrity::Missing => {
+                        inputs.trust(day.number)?;
+                        "recorded".bright_green()
+                    }
+                    // Another account's inputs would be replaced by this one's.
+                    Integrity::Modified | Integrity::Missing
+                        if cli.refetch && !inputs.can_download() =>
+                    {
+                        failures += 1;
+                        "can't refetch".bright_red().bold()
+                    }
+                    Integrity::Modified | Integrity::Missing if cli.refetch => {
+                        inputs.refetch(day.number)?;
+                        "downloaded".bright_green()
+                    }
+                    Integrity::Unchanged => "unchanged".bright_green(),
+                    Integrity::Modified => {
+                        modified += 1;
+                        "modified".bright_red().bold()
+                    }
+                    Integrity::Missing | Integrity::Unknown => integrity.name().dimmed(),
+                })
+            });
+
+            if !print_input_status(day.number, profile, status) {
+                failures += 1;
+            }
+        }
+    }
+    if modified != 0 {
+        eprintln!(
+            "{}",
+            format!("{modified} input(s) modified, use --refetch to download them again, or --record to accept them")
+                .bright_red()
+                .bold()
+        );
+    }
+    if modified != 0 || failures != 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Replaces the selected plaintext inputs with encrypted ones.
+fn encrypt(days: &[Day], cli: &Cli) -> ExitCode {
+    let profiles = match Config::load().and_then(|config| profiles(cli, &config)) {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            print_error(&e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failures = 0;
+    for day in days {
+        if cli.selection.parts(day.number).is_none() {
+            continue;
+        }
+        for profile in &profiles {
+            let status = match profile.inputs.encrypt(day.number) {
+                Ok(()) => Ok("encrypted".bright_green()),
+                Err(Error::IoError(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                    Ok("no plaintext input".dimmed())
+                }
+                Err(e) => Err(e),
+            };
+            if !print_input_status(day.number, profile, status) {
+                failures += 1;
+            }
+        }
+    }
+    if failures != 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Prints a line with the status of an input, returning whether it's not an
+/// error.
+fn print_input_status(day: u32, profile: &Profile, status: Result<ColoredString>) -> bool {
+    print!(
+        "{} {}",
+        "Day".bright_blue(),
+        format!("{day:>2}").bright_red().bold()
+    );
+    if let Some(name) = &profile.name {
+        print!(" {} {}", "::".magenta(), name.bright_cyan());
+    }
+    match status {
+        Ok(status) => {
+            println!(" {} {status}", "::".magenta());
+            true
+        }
+        Err(e) => {
+            println!(" {} {}", "::".magenta(), "error".bright_red());
+            print_error(&e.context(Context::Day(day)));
+            false
+        }
+    }
+}
+
+/// Prints an error, followed by the context it happened in, innermost first.
+fn print_error(e: &Error) {
+    eprintln!("{}: {}", "error".bright_red().bold(), e.root());
+    let contexts = e.contexts().collect::<Vec<_>>();
+    for context in contexts.into_iter().rev() {
+        eprintln!("  {} {context}", "in".dimmed());
+    }
+    if let Some(location) = e.location() {
+        let (source, caret) = location.snippet();
+        eprintln!("  {source}\n  {}", caret.bright_red());
+    }
+}
+
+/// The inputs and expected answers of one account.
+struct Profile {
+    /// Only named when explicitly selected.
+    name: Option<String>,
+    inputs: Inputs,
+    answers: Answers,
+}
+
+fn profiles(cli: &Cli, config: &Config) -> Result<Vec<Profile>> {
+    let inputs_dir = Path::new(cli.inputs_dir.as_deref().unwrap_or("./inputs"));
+    let answers_dir = Path::new("./answers");
+    if let Some(path) = &cli.input {
+        let inputs = match path.as_str() {
+            "-" => Inputs::with_source(StdinSource::default()),
+            path => Inputs::with_source(FileSource::new(path)),
+        };
+        let answers = Answers::with_dir(answers_dir);
+        return Ok(vec![Profile {
+            name: None,
+            inputs,
+            answers,
+        }]);
+    }
+
+    // Only read after `--input`, which doesn't use it.
+    let key = config.input_key()?;
+    // Inputs in another directory are someone else's, so missing ones can't
+    // be downloaded with this session.
+    let directory = |dir: &Path| match cli.inputs_dir {
+        Some(_) => DirectorySource::new(dir).offline(),
+        None => DirectorySource::new(dir),
+    };
+    let names = if cli.all_profiles {
+        let names = inputs::profiles(inputs_dir)?;
+        if names.is_empty() {
+            return Err(Error::InvalidInput(
+                "no profiles found in the inputs directory".into(),
+            ));
+        }
+        names
+    } else {
+        cli.profiles.clone()
+    };
+    if names.is_empty() {
+        return Ok(vec![Profile {
+            name: None,
+            inputs: Inputs::with_source(directory(inputs_dir).with_key(key)).with_config(config),
+            answers: Answers::with_dir(answers_dir),
+        }]);
+    }
+    let profiles = names
+        .into_iter()
+        .map(|name| {
+            let (inputs, answers) = if name == inputs::DEFAULT_PROFILE {
+                (directory(inputs_dir), Answers::with_dir(answers_dir))
+            } else {
+                let inputs = DirectorySource::new(inputs_dir.join(&name)).offline();
+                (inputs, Answers::with_dir(answers_dir.join(&name)))
+            };
+            Profile {
+                name: Some(name),
+                inputs: Inputs::with_source(inputs.with_key(key.clone())).with_config(config),
+                answers,
+            }
+        })
+        .collect();
+    Ok(profiles)
+}
+
+fn execute(days: &[Day], cli: &Cli) -> ExitCode {
+    let setup = Config::load().and_then(|config| Ok((profiles(cli, &config)?, config)));
+    let (profiles, config) = match setup {
+        Ok(setup) => setup,
+        Err(e) => {
+            print_error(&e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let show_times = cli.command == Command::Bench;
+    let mut report = report::reporter(cli.format, config.year, show_times, cli.is_matrix());
+    report.begin();
+
+    let mut repeat = cli.repeat;
+    if cli.command == Command::Bench && !repeat.is_repeated() {
+        repeat.min_time = Some(BENCH_MIN_TIME);
+    }
+
+    let selected = days
+        .iter()
+        .filter_map(|day| Some((day, cli.selection.parts(day.number)?)))
+        .collect::<Vec<_>>();
+    // Runs a day against each profile.
+    let run_day = |day: &Day, parts: &PartFilter| {
+        profiles
+            .iter()
+            .map(|profile| run_profile(day, parts, profile, cli, repeat))
+            .collect::<Vec<_>>()
+    };
+
+    let mut duration = Duration::ZERO;
+    let mut failures = 0;
+    let mut incorrect = 0;
+    let mut unchecked = 0;
+    // Returns whether to continue with the next day.
+    let mut handle_result = |report: &mut dyn Report, results: Vec<DayResult>| {
+        report.days(&results);
+        let mut is_ok = true;
+        for result in &results {
+            duration += result.timings.total();
+            incorrect += result.incorrect_count();
+            unchecked += result.unchecked_count();
+            if !result.is_ok() {
+                failures += 1;
+                is_ok = false;
+            }
+        }
+        is_ok || !cli.fail_fast
+    };
+
+    if cli.parallel {
+        let aborted = AtomicBool::new(false);
+        std::thread::scope(|scope| {
+            let (sender, receiver) = mpsc::channel();
+            scope.spawn(|| {
+                selected.par_iter().enumerate().for_each_with(
+                    sender,
+                    |sender, (index, (day, parts))| {
+                        if !aborted.load(Ordering::Relaxed) {
+                            _ = sender.send((index, run_day(day, parts)));
+                        }
+                    },
+                );
+            });
+
+            // Results arrive in any order, but are reported in day order.
+            let mut pending = BTreeMap::new();
+            let mut next_index = 0;
+            for (index, results) in receiver {
+                pending.insert(index, results);
+                while let Some(results) = pending.remove(&next_index) {
+                    report.begin_day(selected[next_index].0.number);
+                    next_index += 1;
+                    if !handle_result(&mut *report, results) {
+                        aborted.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                }
+            }
+        });
+    } else {
+        for (day, parts) in &selected {
+            report.begin_day(day.number);
+            if !handle_result(&mut *report, run_day(day, parts)) {
+                break;
+            }
+        }
+    }
+    report.end(duration);
+    if failures != 0 {
+        let what = if cli.is_matrix() { "run(s)" } else { "day(s)" };
+        eprintln!(
+            "{}",
+            format!("{failures} {what} failed").bright_red().bold()
+        );
+    }
+    if incorrect != 0 && !cli.record {
+        eprintln!(
+            "{}",
+            format!("{incorrect} answer(s) incorrect")
+                .bright_red()
+                .bold()
+        );
+    }
+    // Unlike `run`, `test` requires every answer to be checked.
+    let is_unchecked = cli.command == Command::Test && unchecked != 0 && !cli.record;
+    if is_unchecked {
+        eprintln!(
+            "{}",
+            format!("{unchecked} answer(s) unchecked, record them with --record")
+                .bright_red()
+                .bold()
+        );
+    }
+    if failures != 0 || (incorrect != 0 && !cli.record) || is_unchecked {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_profile(
+    day: &Day,
+    parts: &PartFilter,
+    profile: &Profile,
+    cli: &Cli,
+    repeat: Repeat,
+) -> DayResult {
+    let mut harness = Harness::new(day.number, repeat, parts.clone(), cli.timeout);
+    let outcome = catch_panic(|| (day.execute)(&profile.inputs, &mut harness)).and_then(|r| r);
+    let mut result = harness.finish(outcome);
+    result.profile = profile.name.clone();
+    if let Ok(Integrity::Modified) = profile.inputs.verify(day.number) {
+        let warning = "input was modified after it was downloaded, run 'verify' to check";
+        result.warnings.push(warning.to_owned());
+    }
+    // Stored answers only apply to the regular inputs, not to a single file.
+    // In another inputs directory, only the profiles have their own answers.
+    let is_own_profile = profile
+        .name
+        .as_deref()
+        .is_some_and(|name| name != inputs::DEFAULT_PROFILE);
+    if cli.command != Command::Bench
+        && cli.input.is_none()
+        && (cli.inputs_dir.is_none() || is_own_profile)
+        && let Err(e) = check_answers(&profile.answers, &mut result, cli.record)
+    {
+        result.error.get_or_insert(e);
+    }
+    result
+}
+
+/// Runs a single day, and again whenever its input changes, printing which
+/// answers changed since the previous run. When the executable is rebuilt,
+/// it's restarted to pick up the changes to the solution.
+fn watch(days: &[Day], cli: &Cli) -> ExitCode {
+    // Validation ensures that a single day is selected.
+    let Some((day, parts)) = days
+        .iter()
+        .find_map(|day| Some((day, cli.selection.parts(day.number)?)))
+    else {
+        unreachable!();
+    };
+
+    let setup = Config::load().and_then(|config| Ok((profiles(cli, &config)?, config)));
+    let (profiles, config) = match setup {
+        Ok(setup) => setup,
+        Err(e) => {
+            print_error(&e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut files = profiles
+        .iter()
+        .flat_map(|profile| profile.inputs.files(day.number))
+        .collect::<Vec<_>>();
+    let executable = std::env::current_exe().ok();
+    files.extend(executable.clone());
+
+    let mut report = report::reporter(cli.format, config.year, false, cli.is_matrix());
+    report.begin();
+    let mut previous = None;
+    loop {
+        report.begin_day(day.number);
+        let results = profiles
+            .iter()
+            .map(|profile| run_profile(day, parts, profile, cli, cli.repeat))
+            .collect::<Vec<_>>();
+        report.days(&results);
+
+        let answers = results
+            .iter()
+            .flat_map(|result| {
+                result.parts.iter().map(|part| {
+                    let answer = match &part.answer {
+                        Ok(answer) => answer.value().into_owned(),
+                        Err(e) => format!("error: {e}"),
+                    };
+                    ((result.profile.clone(), part.name), answer)
+                })
+            })
+            .collect::<BTreeMap<_, _>>();
+        if let Some(previous) = &previous {
+            print_changes(previous, &answers);
+        }
+        previous = Some(answers);
+
+        eprintln!("{}", "watching for changes, press Ctrl-C to stop".dimmed());
+        let changed = wait_for_change(&files);
+        if let Some(executable) = &executable
+            && changed.contains(executable)
+        {
+            return restart(executable);
+        }
+        for path in changed {
+            eprintln!("{} {}", "changed".bright_blue(), path.display());
+        }
+    }
+}
+
+type WatchedAnswers = BTreeMap<(Option<String>, &'static str), String>;
+
+fn print_changes(previous: &WatchedAnswers, current: &WatchedAnswers) {
+    let mut changes = 0;
+    for ((profile, part), answer) in current {
+        let Some(old) = previous.get(&(profile.clone(), *part)) else {
+            continue;
+        };
+        if old == answer {
+            continue;
+        }
+        changes += 1;
+        eprint!("{}", part.bright_yellow());
+        if let Some(profile) = profile {
+            eprint!(" {} {}", "::".magenta(), profile.bright_cyan());
+        }
+        eprintln!(
+            " {} {} {} {}",
+            "::".magenta(),
+            old.dimmed(),
+            "->".magenta(),
+            answer.white().bold()
+        );
+    }
+    if changes == 0 {
+        eprintln!("{}", "answers unchanged".dimmed());
+    }
+}
+
+/// Polls the modification times of files until any of them changes, and
+/// then until they stop changing. Returns the files that changed.
+fn wait_for_change(files: &[PathBuf]) -> Vec<PathBuf> {
+    let modified = || {
+        files
+            .iter()
+            .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect::<Vec<_>>()
+    };
+    let initial = modified();
+    let mut last = initial.clone();
+    loop {
+        std::thread::sleep(WATCH_INTERVAL);
+        let current = modified();
+        if current == last && current != initial {
+            return (files.iter().zip(initial.iter().zip(&current)))
+                .filter(|(_, (before, after))| before != after)
+                .map(|(path, _)| path.clone())
+                .collect();
+        }
+        last = current;
+    }
+}
+
+#[cfg(unix)]
+fn restart(executable: &Path) -> ExitCode {
+    use std::os::unix::process::CommandExt;
+
+    eprintln!("{}", "executable rebuilt, restarting".bright_blue());
+    let e = std::process::Command::new(executable)
+        .args(std::env::args_os().skip(1))
+        .exec();
+    eprintln!("{}: could not restart: {e}", "error".bright_red().bold());
+    ExitCode::FAILURE
+}
+
+#[cfg(not(unix))]
+fn restart(_executable: &Path) -> ExitCode {
+    eprintln!(
+        "{}",
+        "executable rebuilt, run watch again to use it".bright_blue()
+    );
+    ExitCode::SUCCESS
+}
+
+fn submit(days: &[Day], cli: &Cli) -> ExitCode {
+    // Validation ensures that a single part of a single day is selected.
+    let Some((day, parts @ PartFilter::Only(part))) = days
+        .iter()
+        .find_map(|day| Some((day, cli.selection.parts(day.number)?)))
+    else {
+        unreachable!();
+    };
+    let part = part[0].as_str();
+    let level = day.parts.iter().position(|p| *p == part).unwrap() as u32 + 1;
+
+    let setup = Config::load().and_then(|config| Ok((profiles(cli, &config)?, config)));
+    let (profile, config) = match setup {
+        Ok((mut profiles, config)) => (profiles.remove(0), config),
+        Err(e) => {
+            print_error(&e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut report = report::reporter(Format::Text, config.year, false, false);
+    report.begin();
+    report.begin_day(day.number);
+    let mut harness = Harness::new(day.number, Repeat::default(), parts.clone(), cli.timeout);
+    let outcome = (day.execute)(&profile.inputs, &mut harness);
+    let mut result = harness.finish(outcome);
+    if let Err(e) = check_answers(&profile.answers, &mut result, false) {
+        result.error.get_or_insert(e);
+    }
+    report.day(&result);
+    let answer = match &result.parts[..] {
+        [
+            PartResult {
+                answer: Ok(answer), ..
+            },
+        ] if result.error.is_none() => answer.value().into_owned(),
+        // The error was already reported.
+        _ => return ExitCode::FAILURE,
+    };
+
+    let answer = answer.as_str();
+    let submit = || -> Result<Outcome> {
+        if answer.contains('\n') {
+            return Err(Error::InvalidInput(
+                "multi-line answers can't be submitted".into(),
+            ));
+        }
+        let submissions = Submissions::new();
+        if let Some(refusal) = submissions.check(day.number, part, answer)? {
+            return Err(refusal.into());
+        }
+        let outcome = Outcome::parse(&profile.inputs.submit(day.number, level, answer)?);
+        submissions.record(day.number, part, answer, outcome)?;
+        if outcome == Outcome::Correct {
+            profile.answers.record(day.number, [(part, answer)])?;
+        }
+        Ok(outcome)
+    };
+    let part = day.parts[level as usize - 1];
+    let outcome = submit()
+        .context(Context::Part(part))
+        .context(Context::Day(day.number));
+    match outcome {
+        Ok(outcome) => {
+            let text = outcome.to_string();
+            let text = match outcome {
+                Outcome::Correct => text.bright_green().bold(),
+                Outcome::Wait(_) | Outcome::WrongLevel | Outcome::Unrecognized => text.yellow(),
+                _ => text.bright_red().bold(),
+            };
+            println!(
+                "{} {} {text}",
+                "Submitted".bright_blue(),
+                answer.white().bold()
+            );
+            match outcome {
+                Outcome::Correct => ExitCode::SUCCESS,
+                _ => ExitCode::FAILURE,
+            }
+        }
+        Err(e) => {
+            print_error(&e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Fills in the expected answers of a day, and if requested, records the
+/// current answers as the new expected ones.
+fn check_answers(answers: &Answers, result: &mut DayResult, record: bool) -> Result<()> {
+    let expected = answers.get(result.day)?;
+    for part in &mut result.parts {
+        part.expected = expected
+            .iter()
+            .find(|(name, _)| name == part.name)
+            .map(|(_, answer)| answer.clone());
+    }
+    if record {
+        let current = result
+            .parts
+            .iter()
+            .filter_map(|part| Some((part.name, part.answer.as_ref().ok()?.value())))
+            .collect::<Vec<_>>();
+        let current = current.iter().map(|(name, answer)| (*name, &**answer));
+        answers.record(result.day, current)?;
+    }
+    Ok(())
+}
+
+/// Returns an example of a day's puzzle description, for use in tests.
+pub fn example(day: u32, index: usize) -> &'static [u8] {
+    let config = Config::load().expect("invalid config");
+    match Inputs::new().with_config(&config).example(day, index) {
+        Ok(example) => example.leak(),
+        Err(e) => panic!("could not get example {index} of day {day}: {e}"),
+    }
+}
+
+/// The expected output of a `test_pt!`, converted to an answer to compare
+/// with the result. Of the same type as the result where it can be, so that
+/// integer literals take the type of the result, instead of `i32`.
+#[doc(hidden)]
+pub struct ExpectedOutput<R, O>(PhantomData<R>, O);
+
+impl<R, O> ExpectedOutput<R, O> {
+    pub fn new(_result: &R, output: O) -> ExpectedOutput<R, O> {
+        ExpectedOutput(PhantomData, output)
+    }
+}
+
+/// Preferred by method resolution when the output has the type of the result.
+#[doc(hidden)]
+pub trait SameTypeOutput {
+    fn answer(&self) -> Answer;
+}
+
+impl<R: Clone + Into<Answer>> SameTypeOutput for ExpectedOutput<R, R> {
+    fn answer(&self) -> Answer {
+        self.1.clone().into()
+    }
+}
+
+/// Used for outputs of another type, such as a number for an expression.
+#[doc(hidden)]
+pub trait OtherTypeOutput {
+    fn answer(&self) -> Answer;
+}
+
+impl<R, O: Clone + Into<Answer>> OtherTypeOutput for &ExpectedOutput<R, O> {
+    fn answer(&self) -> Answer {
+        self.1.clone().into()
+    }
+}
+
+#[macro_export]
+macro_rules! main {
+    ($($day:ident),*$(,)?) => {
+        $(mod $day;)*
+
+        fn main() -> std::process::ExitCode {
+            $crate::runner::main(&[$($day::DayMetadata::DAY),*])
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! day {
+    ($day_nr:literal, $parse_fn:ident => $($part_fn:ident),+) => {
+use super::prelude::*;
+pub struct DayMetadata;
+impl DayMetadata {
+    pub const DAY: $crate::runner::Day = $crate::runner::Day {
+        number: $day_nr,
+        parts: &[$(stringify!($part_fn)),+],
+        execute: Self::execute,
+    };
+
+    pub fn number() -> u32 { $day_nr }
+    pub fn execute(inputs: &$crate::runner::Inputs, harness: &mut $crate::runner::Harness) -> $crate::runner::Result<()> {
+        use $crate::runner::*;
+        let input = inputs.get($day_nr)?;
+        let (parsed, parse_time) = harness.measure(|| catch_panic(|| $parse_fn(&input)));
+        let parsed = harness.record_parse(parsed.and_then(|parsed| parsed), parse_time)?;
+        $(if harness.includes(stringify!($part_fn)) {
+            let (result, time) = harness.measure(|| catch_panic(|| $part_fn(&parsed)));
+            let answer = result.and_then(IntoResult::into_result).map(Answer::from);
+            harness.record_part(stringify!($part_fn), answer, time);
+        })+
+        Ok(())
+    }
+}
+$crate::paste! {
+    #[cfg(feature = "criterion")]
+    #[criterion_macro::criterion]
+    pub fn benchmarks(c: &mut criterion::Criterion) {
+        use criterion::Criterion;
+        let config = $crate::config::Config::load().expect("invalid config");
+        let inputs = $crate::inputs::Inputs::from_config(&config).expect("invalid input key");
+        let input = inputs.get($day_nr).expect("could not get input");
+        let parsed = $parse_fn(&input).expect("could not parse input");
+        c.bench_function(stringify!([<day $day_nr _ $parse_fn>]), |b| b.iter(|| $parse_fn(&input)));
+        $(
+            c.bench_function(stringify!([<day $day_nr _ $part_fn>]), |b| b.iter(|| $part_fn(&parsed)));
+        )*
+    }
+}
+    };
+}
+
+#[macro_export]
+macro_rules! if_test {
+    ($if:expr, $else:expr) => {
+        if cfg!(all(test, not(feature = "criterion"))) {
+            $if
+        } else {
+            $else
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! tests {
+    ($($x:tt)*) => {
+        #[cfg(test)]
+        #[cfg(not(feature = "criterion"))]
+        mod tests {
+            use super::*;
+            use $crate::test_pt;
+
+            /// The `index`th example (starting at 1) of the puzzle description.
+            #[allow(dead_code)]
+            fn example(index: usize) -> &'static [u8] {
+                $crate::runner::example(super::DayMetadata::number(), index)
+            }
+
+            $($x)*
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! test_pt {
+    ($parse_fn:ident, $test_name:ident, |$input_name:ident| $part_logic:block, $($input:expr => $output:expr),+$(,)?) => {
+#[test]
+fn $test_name() {
+    use $crate::runner::*;
+    $(
+        let $input_name = match IntoResult::into_result(super::$parse_fn($input)) {
+            Ok(x) => x,
+            Err(e) => panic!("parsing failed: {e}\ninput: {:?}", String::from_utf8_lossy($input).into_owned()),
+        };
+        let result = match IntoResult::into_result($part_logic) {
+            Ok(x) => x,
+            Err(e) => panic!("execution failed: {e}\ninput: {:?}", String::from_utf8_lossy($input).into_owned()),
+        };
+        let output = (&ExpectedOutput::new(&result, $output)).answer();
+        let result = Answer::from(result);
+        if result.value() != output.value() {
+            panic!("incorrect output, expected: {}, got: {}\ninput: {:?}", output.plain(), result.plain(), String::from_utf8_lossy($input).into_owned());
+        }
+    )+
+}
+    };
+    ($parse_fn:ident, $pt_fn:ident, $($input:expr => $output:expr),+$(,)?) => {
+        $crate::test_pt!($parse_fn, $pt_fn, |input| { super::$pt_fn(&input) }, $($input => $output),+);
+    };
+}
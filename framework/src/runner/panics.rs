@@ -0,0 +1,71 @@
+use crate::{cancel::Cancelled, error::Error, result::Result};
+use std::{
+    cell::{Cell, RefCell},
+    panic::{AssertUnwindSafe, PanicHookInfo},
+    sync::Once,
+};
+
+thread_local! {
+    /// Whether a panic on this thread will be caught by `catch_panic`.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last caught panic happened.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, turning a panic into an error, so a single bug doesn't abort
+/// the whole run. Cancelled parts unwind as well, and become errors too.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T> {
+    install_hook();
+    let was_catching = CATCHING.replace(true);
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.map_err(|payload| {
+        if let Some(cancelled) = payload.downcast_ref::<Cancelled>() {
+            return match cancelled.timeout {
+                Some(timeout) => Error::TimedOut(timeout),
+                None => Error::Cancelled,
+            };
+        }
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+        match LOCATION.take() {
+            Some(location) => Error::Panic(format!("{location}: {message}")),
+            None => Error::Panic(message.to_owned()),
+        }
+    })
+}
+
+/// Records the location of caught panics instead of printing them, since
+/// they're reported along with the other errors. Other panics are printed
+/// as usual.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info: &PanicHookInfo| {
+            if CATCHING.get() {
+                let location = info.location().map(|l| l.to_string());
+                LOCATION.set(location);
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn panics() {
+        assert_eq!(5, catch_panic(|| 5).unwrap());
+        let error = catch_panic(|| -> u32 { panic!("oops {}", 1) }).unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("panicked at framework/src/runner/panics.rs:"));
+        assert!(message.ends_with(": oops 1"));
+    }
+}
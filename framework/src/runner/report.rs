@@ -0,0 +1,811 @@
+use super::{stats::Stats, terminal};
+use crate::{
+    answers::Verdict,
+    error::{Context, Error},
+    outputs::Answer,
+    result::Result,
+    util::display_width,
+};
+use colored::Colorize;
+use std::{fmt::Write as _, io::Write, time::Duration};
+
+/// Width of the part name and answer, unless the terminal is too narrow.
+const OUTPUT_WIDTH: usize = 40;
+const MIN_OUTPUT_WIDTH: usize = 20;
+/// Width of the `Day NN` at the start of a line.
+const DAY_WIDTH: usize = 6;
+const TIME_WIDTH: usize = 9;
+/// Number of entries in the table of slowest functions printed at the end.
+const SLOWEST_COUNT: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Ndjson,
+}
+
+impl Format {
+    pub(super) fn from_name(name: &str) -> Option<Format> {
+        Some(match name {
+            "text" => Format::Text,
+            "json" => Format::Json,
+            "ndjson" => Format::Ndjson,
+            _ => return None,
+        })
+    }
+}
+
+/// Whether output is colored, set with `--color`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Colored, unless stdout isn't a terminal or `NO_COLOR` is set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub(super) fn from_name(name: &str) -> Option<ColorChoice> {
+        Some(match name {
+            "auto" => ColorChoice::Auto,
+            "always" => ColorChoice::Always,
+            "never" => ColorChoice::Never,
+            _ => return None,
+        })
+    }
+
+    pub fn apply(self) {
+        match self {
+            // `colored` already checks the terminal and environment.
+            ColorChoice::Auto => colored::control::unset_override(),
+            ColorChoice::Always => colored::control::set_override(true),
+            ColorChoice::Never => colored::control::set_override(false),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub name: &'static str,
+    pub answer: Result<Answer>,
+    /// The previously recorded answer, if any.
+    pub expected: Option<String>,
+}
+
+impl PartResult {
+    /// Whether the answer matches the recorded one. A part that failed has
+    /// no answer to check, so its verdict is unknown.
+    pub fn verdict(&self) -> Verdict {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) | (_, None) => Verdict::Unknown,
+            (Ok(answer), Some(expected)) if answer.matches(expected) => Verdict::Correct,
+            (Ok(_), Some(_)) => Verdict::Incorrect,
+        }
+    }
+}
+
+/// Time spent in the parse function and each part of a day, excluding IO.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    pub parse: Stats,
+    pub parts: Vec<(&'static str, Stats)>,
+}
+
+impl Timings {
+    pub fn part(&self, name: &str) -> Option<Stats> {
+        self.parts
+            .iter()
+            .find(|(part, _)| *part == name)
+            .map(|(_, stats)| *stats)
+    }
+
+    /// Sum of the mean times of all functions.
+    pub fn total(&self) -> Duration {
+        self.parse.mean
+            + self
+                .parts
+                .iter()
+                .map(|(_, stats)| stats.mean)
+                .sum::<Duration>()
+    }
+}
+
+/// Everything collected while executing a single day.
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u32,
+    pub timings: Timings,
+    pub parts: Vec<PartResult>,
+    /// The profile whose input was used, if one was selected.
+    pub profile: Option<String>,
+    /// Problems that didn't prevent the day from running.
+    pub warnings: Vec<String>,
+    /// Failure that prevented the day from running, such as a missing input
+    /// or a parsing error.
+    pub error: Option<Error>,
+}
+
+impl DayResult {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.parts.iter().all(|part| part.answer.is_ok())
+    }
+
+    pub fn incorrect_count(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| part.verdict() == Verdict::Incorrect)
+            .count()
+    }
+
+    /// Number of parts with an answer, but no recorded one to check it with.
+    pub fn unchecked_count(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| part.answer.is_ok() && part.verdict() == Verdict::Unknown)
+            .count()
+    }
+}
+
+/// Renders the results of a run.
+pub trait Report {
+    fn begin(&mut self) {}
+    fn begin_day(&mut self, _day: u32) {}
+    fn day(&mut self, result: &DayResult);
+    /// Renders a day that was run against several profiles.
+    fn days(&mut self, results: &[DayResult]) {
+        for result in results {
+            self.day(result);
+        }
+    }
+    fn end(&mut self, _total: Duration) {}
+}
+
+pub fn reporter(format: Format, year: u32, show_times: bool, matrix: bool) -> Box<dyn Report> {
+    match format {
+        Format::Text if matrix => Box::new(MatrixReport { year, show_times }),
+        Format::Text => Box::new(TextReport {
+            year,
+            show_times,
+            timings: Vec::new(),
+            width: terminal::width(),
+            column: 0,
+        }),
+        Format::Json => Box::new(JsonReport {
+            records: Some(Vec::new()),
+        }),
+        Format::Ndjson => Box::new(JsonReport { records: None }),
+    }
+}
+
+fn print_banner(year: u32) {
+    println!(
+        "\n🎄 {} {} {} {} 🎄\n",
+        "Advent".bright_red().bold(),
+        "of".bright_green(),
+        "Code".blue().bold(),
+        year.to_string().bright_magenta().bold()
+    );
+}
+
+fn print_day(day: u32) {
+    print!(
+        "{} {}",
+        "Day".bright_blue(),
+        format!("{day:>2}").bright_red().bold()
+    );
+}
+
+/// Prints the lines of a multi-line answer as an indented block.
+fn print_lines(lines: &[String]) {
+    for line in lines {
+        println!("          {}", line.white().bold());
+    }
+}
+
+/// Prints the context of an error, innermost first, and for parse errors,
+/// the input around where parsing failed.
+fn print_error_details(error: &Error) {
+    for context in error_context(error) {
+        println!("          {} {context}", "in".dimmed());
+    }
+    if let Some(location) = error.location() {
+        let (source, caret) = location.snippet();
+        println!("          {source}");
+        println!("          {}", caret.bright_red());
+    }
+}
+
+/// Colored, column-aligned output for the terminal.
+struct TextReport {
+    year: u32,
+    /// Print parse and part times instead of the answers (used by `bench`).
+    show_times: bool,
+    timings: Vec<(u32, &'static str, Duration)>,
+    /// Width of the terminal, or `None` when not printing to one.
+    width: Option<usize>,
+    /// Width of what's been printed on the current line.
+    column: usize,
+}
+
+impl TextReport {
+    /// Prints the column of a part, with its value right-aligned, followed
+    /// by the suffix. Columns that don't fit on the line in the terminal
+    /// start a new one, and values that don't fit in their column continue
+    /// on the next line.
+    fn print_column(
+        &mut self,
+        name: &str,
+        value: &str,
+        value_width: usize,
+        suffix: &str,
+        suffix_width: usize,
+    ) {
+        let name_width = display_width(name);
+        let Some(width) = self.width else {
+            let padding = OUTPUT_WIDTH.saturating_sub(name_width + 1 + value_width);
+            print!(
+                " {} {} {:padding$}{value}{suffix}",
+                "::".magenta(),
+                name.bright_yellow(),
+                ""
+            );
+            return;
+        };
+
+        let output_width = OUTPUT_WIDTH
+            .min(width.saturating_sub(DAY_WIDTH + 4 + suffix_width))
+            .max(MIN_OUTPUT_WIDTH);
+        let fits = name_width + 1 + value_width <= output_width;
+        let column_width = match fits {
+            true => 4 + output_width + suffix_width,
+            false => 4 + name_width,
+        };
+        if self.column > DAY_WIDTH && self.column + column_width > width {
+            print!("\n{:DAY_WIDTH$}", "");
+            self.column = DAY_WIDTH;
+        }
+        print!(" {} {}", "::".magenta(), name.bright_yellow());
+        if fits {
+            let padding = output_width - name_width - 1 - value_width;
+            print!(" {:padding$}{value}{suffix}", "");
+            self.column += column_width;
+        } else {
+            let indent = DAY_WIDTH + 4;
+            print!("\n{:indent$}{value}{suffix}", "");
+            self.column = indent + value_width + suffix_width;
+        }
+    }
+
+    fn print_time(&mut self, name: &str, time: Duration) {
+        let time = format!("{time:.2?}");
+        self.print_column(name, &time.white().to_string(), time.len(), "", 0);
+    }
+
+    fn print_stats(name: &str, stats: &Stats) {
+        println!(
+            "       {:<8} {} {:>8} {} {:>TIME_WIDTH$} {} {:>TIME_WIDTH$} {} {:>TIME_WIDTH$} {} {:>TIME_WIDTH$}",
+            name.bright_yellow(),
+            "n".dimmed(),
+            stats.samples,
+            "min".dimmed(),
+            format!("{:.2?}", stats.min),
+            "median".dimmed(),
+            format!("{:.2?}", stats.median),
+            "mean".dimmed(),
+            format!("{:.2?}", stats.mean),
+            "σ".dimmed(),
+            format!("{:.2?}", stats.stddev),
+        );
+    }
+
+    fn print_slowest(&mut self, total: Duration) {
+        self.timings
+            .sort_by_key(|&(_, _, time)| std::cmp::Reverse(time));
+        println!("{}", "Slowest".bright_blue());
+        for &(day, name, time) in self.timings.iter().take(SLOWEST_COUNT) {
+            let share = time.as_secs_f64() / total.as_secs_f64().max(f64::MIN_POSITIVE) * 100.0;
+            println!(
+                "{} {} {} {:<8}{:>TIME_WIDTH$} {}",
+                "Day".bright_blue(),
+                format!("{day:>2}").bright_red().bold(),
+                "::".magenta(),
+                name.bright_yellow(),
+                format!("{time:.2?}").white(),
+                format!("{share:>5.1}%").dimmed()
+            );
+        }
+    }
+}
+
+impl Report for TextReport {
+    fn begin(&mut self) {
+        print_banner(self.year);
+    }
+
+    fn begin_day(&mut self, day: u32) {
+        print_day(day);
+        self.column = DAY_WIDTH;
+        _ = std::io::stdout().flush();
+    }
+
+    fn day(&mut self, result: &DayResult) {
+        let timings = &result.timings;
+        if result.error.is_none() {
+            self.timings.push((result.day, "parse", timings.parse.mean));
+            if self.show_times {
+                self.print_time("parse", timings.parse.mean);
+            }
+        }
+        for &(name, stats) in &timings.parts {
+            self.timings.push((result.day, name, stats.mean));
+        }
+        for part in &result.parts {
+            let time = timings.part(part.name).unwrap_or_default().mean;
+            match &part.answer {
+                Ok(_) if self.show_times => self.print_time(part.name, time),
+                Ok(answer) => {
+                    let mark = match part.verdict() {
+                        Verdict::Correct => "✓".bright_green(),
+                        Verdict::Incorrect => "✗".bright_red().bold(),
+                        Verdict::Unknown => "?".dimmed(),
+                    };
+                    let time = format!("{time:.2?}");
+                    let suffix = format!(" {mark} {:>TIME_WIDTH$}", time.dimmed());
+                    let suffix_width = 3 + TIME_WIDTH.max(time.chars().count());
+                    let (value, value_width) = match answer.lines() {
+                        // Printed beneath the day instead.
+                        Some(lines) => {
+                            let value = format!("{} lines", lines.len());
+                            (value.dimmed().to_string(), value.len())
+                        }
+                        None => (answer.styled(), answer.width()),
+                    };
+                    self.print_column(part.name, &value, value_width, &suffix, suffix_width);
+                }
+                Err(e) => {
+                    let e = e.root().to_string();
+                    let width = display_width(&e);
+                    self.print_column(part.name, &e.bright_red().to_string(), width, "", 0);
+                }
+            }
+        }
+        if let Some(e) = &result.error {
+            let e = e.root().to_string();
+            let width = display_width(&e);
+            self.print_column("error", &e.bright_red().to_string(), width, "", 0);
+        }
+        println!();
+        self.column = 0;
+        if timings.parse.samples > 1 {
+            Self::print_stats("parse", &timings.parse);
+        }
+        for (name, stats) in &timings.parts {
+            if stats.samples > 1 {
+                Self::print_stats(name, stats);
+            }
+        }
+        if let Some(e) = &result.error {
+            print_error_details(e);
+        }
+        for part in &result.parts {
+            if !self.show_times
+                && let Ok(answer) = &part.answer
+                && let Some(lines) = answer.lines()
+            {
+                println!("       {}", part.name.bright_yellow());
+                print_lines(lines);
+            }
+            if let Err(e) = &part.answer {
+                print_error_details(e);
+            }
+            if let (Ok(_), Some(expected), Verdict::Incorrect) =
+                (&part.answer, &part.expected, part.verdict())
+            {
+                println!(
+                    "       {} {} {}",
+                    part.name.bright_yellow(),
+                    "expected".bright_red(),
+                    expected.white().bold()
+                );
+            }
+        }
+        for warning in &result.warnings {
+            eprintln!("{}: {warning}", "warning".yellow().bold());
+        }
+    }
+
+    fn end(&mut self, total: Duration) {
+        println!();
+        if self.timings.len() > 1 {
+            self.print_slowest(total);
+            println!();
+        }
+        println!("{:?}", total);
+    }
+}
+
+/// A table per day, with a row per part, and a column per profile.
+struct MatrixReport {
+    year: u32,
+    /// Print the part times instead of the answers (used by `bench`).
+    show_times: bool,
+}
+
+impl MatrixReport {
+    /// Renders a cell, returning it with its printed width.
+    fn cell(&self, result: &DayResult, part: &str) -> (String, usize) {
+        let Some(part) = result.parts.iter().find(|p| p.name == part) else {
+            return ("-".dimmed().to_string(), 1);
+        };
+        match &part.answer {
+            Ok(_) if self.show_times => {
+                let time = result.timings.part(part.name).unwrap_or_default().mean;
+                let time = format!("{time:.2?}");
+                (time.white().to_string(), time.chars().count())
+            }
+            Ok(answer) => {
+                let mark = match part.verdict() {
+                    Verdict::Correct => "✓".bright_green(),
+                    Verdict::Incorrect => "✗".bright_red().bold(),
+                    Verdict::Unknown => "?".dimmed(),
+                };
+                if let Some(lines) = answer.lines() {
+                    let value = format!("{} lines", lines.len());
+                    return (format!("{} {mark}", value.dimmed()), value.len() + 2);
+                }
+                let width = answer.width() + 2;
+                (format!("{} {mark}", answer.styled()), width)
+            }
+            Err(_) => ("error".bright_red().to_string(), 5),
+        }
+    }
+}
+
+impl Report for MatrixReport {
+    fn begin(&mut self) {
+        print_banner(self.year);
+    }
+
+    fn day(&mut self, result: &DayResult) {
+        self.days(std::slice::from_ref(result));
+    }
+
+    fn days(&mut self, results: &[DayResult]) {
+        let Some(first) = results.first() else {
+            return;
+        };
+        let mut parts = Vec::new();
+        for part in results.iter().flat_map(|result| &result.parts) {
+            if !parts.contains(&part.name) {
+                parts.push(part.name);
+            }
+        }
+        let names = results
+            .iter()
+            .map(|result| result.profile.as_deref().unwrap_or(""))
+            .collect::<Vec<_>>();
+        let rows = parts
+            .iter()
+            .map(|part| {
+                let cells = results.iter().map(|result| self.cell(result, part));
+                (*part, cells.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let widths = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let cells = rows.iter().map(|(_, cells)| cells[i].1);
+                cells.fold(display_width(name), usize::max)
+            })
+            .collect::<Vec<_>>();
+
+        print_day(first.day);
+        let mut line = String::new();
+        for (name, width) in names.iter().zip(&widths) {
+            _ = write!(line, " {} {:<width$}", "::".magenta(), name.bright_cyan());
+        }
+        println!("{}", line.trim_end());
+        for (part, cells) in &rows {
+            let mut line = format!("{:>6}", part.bright_yellow());
+            for ((cell, cell_width), width) in cells.iter().zip(&widths) {
+                let padding = width - cell_width;
+                _ = write!(line, " {} {cell}{:padding$}", "::".magenta(), "");
+            }
+            println!("{}", line.trim_end());
+        }
+
+        for (result, name) in results.iter().zip(&names) {
+            for part in &result.parts {
+                if !self.show_times
+                    && let Ok(answer) = &part.answer
+                    && let Some(lines) = answer.lines()
+                {
+                    println!(
+                        "       {} {}",
+                        name.bright_cyan(),
+                        part.name.bright_yellow()
+                    );
+                    print_lines(lines);
+                }
+                match (&part.answer, &part.expected, part.verdict()) {
+                    (Err(e), _, _) => {
+                        eprintln!(
+                            "       {} {}: {}",
+                            name.bright_cyan(),
+                            part.name.bright_yellow(),
+                            e.root()
+                        );
+                        print_error_details(e);
+                    }
+                    (Ok(_), Some(expected), Verdict::Incorrect) => println!(
+                        "       {} {} {} {}",
+                        name.bright_cyan(),
+                        part.name.bright_yellow(),
+                        "expected".bright_red(),
+                        expected.white().bold()
+                    ),
+                    _ => {}
+                }
+            }
+            for warning in &result.warnings {
+                eprintln!(
+                    "{} {}: {warning}",
+                    "warning".yellow().bold(),
+                    name.bright_cyan()
+                );
+            }
+            if let Some(e) = &result.error {
+                eprintln!(
+                    "{} {}: {}",
+                    "error".bright_red().bold(),
+                    name.bright_cyan(),
+                    e.root()
+                );
+                print_error_details(e);
+            }
+        }
+    }
+
+    fn end(&mut self, total: Duration) {
+        println!("\n{:?}", total);
+    }
+}
+
+/// One JSON object per part, either collected into an array, or written
+/// as newline-delimited JSON as each day completes.
+struct JsonReport {
+    records: Option<Vec<String>>,
+}
+
+impl Report for JsonReport {
+    fn day(&mut self, result: &DayResult) {
+        let mut records = Vec::new();
+        if let Some(e) = &result.error {
+            records.push(json_record(result, None, None, Some(e)));
+        }
+        for part in &result.parts {
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (Some(answer), None),
+                Err(e) => (None, Some(e)),
+            };
+            records.push(json_record(result, Some(part), answer, error));
+        }
+
+        match &mut self.records {
+            Some(all) => all.extend(records),
+            None => {
+                let mut stdout = std::io::stdout().lock();
+                for record in records {
+                    _ = writeln!(stdout, "{record}");
+                }
+            }
+        }
+    }
+
+    fn end(&mut self, _total: Duration) {
+        if let Some(records) = &self.records {
+            println!("[");
+            for (i, record) in records.iter().enumerate() {
+                let separator = if i + 1 == records.len() { "" } else { "," };
+                println!("  {record}{separator}");
+            }
+            println!("]");
+        }
+    }
+}
+
+fn json_record(
+    day: &DayResult,
+    part: Option<&PartResult>,
+    answer: Option<&Answer>,
+    error: Option<&Error>,
+) -> String {
+    let mut record = format!("{{\"day\":{}", day.day);
+    _ = write!(
+        record,
+        ",\"profile\":{}",
+        json_string(day.profile.as_deref())
+    );
+    _ = write!(
+        record,
+        ",\"part\":{}",
+        json_string(part.map(|part| part.name))
+    );
+    let value = answer.map(|answer| answer.value());
+    _ = write!(record, ",\"answer\":{}", json_string(value.as_deref()));
+    match answer {
+        Some(answer) => _ = write!(record, ",\"answer_detail\":{}", json_answer(answer)),
+        None => record.push_str(",\"answer_detail\":null"),
+    }
+    let timings = &day.timings;
+    _ = write!(
+        record,
+        ",\"parse_time_ns\":{}",
+        timings.parse.mean.as_nanos()
+    );
+    let part_stats = part.and_then(|part| timings.part(part.name));
+    match part_stats {
+        Some(stats) => _ = write!(record, ",\"part_time_ns\":{}", stats.mean.as_nanos()),
+        None => record.push_str(",\"part_time_ns\":null"),
+    }
+    _ = write!(
+        record,
+        ",\"parse_stats\":{}",
+        json_stats(Some(&timings.parse))
+    );
+    _ = write!(
+        record,
+        ",\"part_stats\":{}",
+        json_stats(part_stats.as_ref())
+    );
+    match part {
+        Some(part) => _ = write!(record, ",\"status\":\"{}\"", part.verdict().name()),
+        None => record.push_str(",\"status\":null"),
+    }
+    let expected = part.and_then(|part| part.expected.as_deref());
+    _ = write!(record, ",\"expected\":{}", json_string(expected));
+    let warnings = day.warnings.iter().map(|w| json_string(Some(w)));
+    _ = write!(
+        record,
+        ",\"warnings\":[{}]",
+        warnings.collect::<Vec<_>>().join(",")
+    );
+    let message = error.map(|e| e.root().to_string());
+    _ = write!(record, ",\"error\":{}", json_string(message.as_deref()));
+    let context = error.map_or(Vec::new(), error_context);
+    let context = context.iter().map(|c| json_string(Some(&c.to_string())));
+    _ = write!(
+        record,
+        ",\"error_context\":[{}]}}",
+        context.collect::<Vec<_>>().join(",")
+    );
+    record
+}
+
+/// The context an error happened in, innermost first.
+fn error_context(error: &Error) -> Vec<&Context> {
+    let mut context = error.contexts().collect::<Vec<_>>();
+    context.reverse();
+    context
+}
+
+/// The structure of an answer, such as the operands of an expression.
+fn json_answer(answer: &Answer) -> String {
+    let strings = |values: &[String]| {
+        let values = values.iter().map(|value| json_string(Some(value)));
+        format!("[{}]", values.collect::<Vec<_>>().join(","))
+    };
+    match answer {
+        Answer::Scalar(value) => format!(
+            "{{\"kind\":\"scalar\",\"value\":{}}}",
+            json_string(Some(value))
+        ),
+        Answer::Text(value) => format!(
+            "{{\"kind\":\"text\",\"value\":{}}}",
+            json_string(Some(value))
+        ),
+        Answer::Expression {
+            operator,
+            operands,
+            value,
+        } => format!(
+            "{{\"kind\":\"expression\",\"operator\":{},\"operands\":{},\"value\":{}}}",
+            json_string(Some(operator.symbol())),
+            strings(operands),
+            json_string(Some(value))
+        ),
+        Answer::Grid(lines) => format!("{{\"kind\":\"grid\",\"lines\":{}}}", strings(lines)),
+    }
+}
+
+fn json_stats(stats: Option<&Stats>) -> String {
+    let Some(stats) = stats else {
+        return "null".to_owned();
+    };
+    format!(
+        "{{\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+        stats.samples,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_owned();
+    };
+    let mut s = String::with_capacity(value.len() + 2);
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => _ = write!(s, "\\u{:04x}", c as u32),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json_records() {
+        let result = DayResult {
+            day: 3,
+            timings: Timings {
+                parse: Stats::from_samples(&mut [Duration::from_nanos(1500)]),
+                parts: vec![
+                    ("pt1", Stats::from_samples(&mut [Duration::from_nanos(20)])),
+                    (
+                        "pt2",
+                        Stats::from_samples(&mut [30, 10].map(Duration::from_nanos)),
+                    ),
+                ],
+            },
+            parts: vec![
+                PartResult {
+                    name: "pt1",
+                    answer: Ok(Answer::from("a \"quoted\"\nvalue".to_owned())),
+                    expected: None,
+                },
+                PartResult {
+                    name: "pt2",
+                    answer: Err(Error::NoSolution
+                        .context(Context::Part("pt2"))
+                        .context(Context::Day(3))),
+                    expected: Some("12".to_owned()),
+                },
+            ],
+            profile: None,
+            warnings: vec!["input was \"modified\"".to_owned()],
+            error: None,
+        };
+        let pt1 = &result.parts[0];
+        let pt2 = &result.parts[1];
+        assert_eq!(
+            r#"{"day":3,"profile":null,"part":"pt1","answer":"a \"quoted\"\nvalue","answer_detail":{"kind":"grid","lines":["a \"quoted\"","value"]},"parse_time_ns":1500,"part_time_ns":20,"parse_stats":{"samples":1,"min_ns":1500,"median_ns":1500,"mean_ns":1500,"stddev_ns":0},"part_stats":{"samples":1,"min_ns":20,"median_ns":20,"mean_ns":20,"stddev_ns":0},"status":"unknown","expected":null,"warnings":["input was \"modified\""],"error":null,"error_context":[]}"#,
+            json_record(&result, Some(pt1), pt1.answer.as_ref().ok(), None)
+        );
+        assert_eq!(
+            r#"{"day":3,"profile":null,"part":"pt2","answer":null,"answer_detail":null,"parse_time_ns":1500,"part_time_ns":20,"parse_stats":{"samples":1,"min_ns":1500,"median_ns":1500,"mean_ns":1500,"stddev_ns":0},"part_stats":{"samples":2,"min_ns":10,"median_ns":20,"mean_ns":20,"stddev_ns":14},"status":"unknown","expected":"12","warnings":["input was \"modified\""],"error":"no solution","error_context":["pt2","day 3"]}"#,
+            json_record(&result, Some(pt2), None, pt2.answer.as_ref().err())
+        );
+        assert_eq!(
+            r#"{"day":3,"profile":null,"part":null,"answer":null,"answer_detail":null,"parse_time_ns":1500,"part_time_ns":null,"parse_stats":{"samples":1,"min_ns":1500,"median_ns":1500,"mean_ns":1500,"stddev_ns":0},"part_stats":null,"status":null,"expected":null,"warnings":["input was \"modified\""],"error":"not yet implemented","error_context":[]}"#,
+            json_record(&result, None, None, Some(&Error::NotImplemented))
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+/// Upper bound on the number of samples taken of a single function.
+const MAX_SAMPLES: usize = 100_000;
+
+/// How often each parse and part function is executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Repeat {
+    /// Minimum number of executions.
+    pub count: Option<u32>,
+    /// Keep executing until at least this much time was spent.
+    pub min_time: Option<Duration>,
+}
+
+impl Repeat {
+    pub fn is_repeated(&self) -> bool {
+        self.count.is_some_and(|count| count > 1) || self.min_time.is_some()
+    }
+
+    /// Runs `f` as often as configured, returning the output of the last
+    /// execution, and the statistics of all of them.
+    pub fn measure<T>(&self, mut f: impl FnMut() -> T) -> (T, Stats) {
+        let min_count = self.count.unwrap_or(1) as usize;
+        let min_time = self.min_time.unwrap_or_default();
+        let mut samples = Vec::with_capacity(min_count);
+        let mut total = Duration::ZERO;
+        let mut result;
+        loop {
+            let before = Instant::now();
+            result = f();
+            let time = before.elapsed();
+            samples.push(time);
+            total += time;
+            let is_done = samples.len() >= min_count && total >= min_time;
+            if is_done || samples.len() >= MAX_SAMPLES {
+                break;
+            }
+        }
+        (result, Stats::from_samples(&mut samples))
+    }
+}
+
+/// Summary of the execution times of a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub samples: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = if n > 1 {
+            let mean = mean.as_secs_f64();
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Stats {
+            samples: n as u32,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Parses durations such as `500ms`, `2s` or `1.5` (seconds).
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value = value.parse::<f64>().ok()?;
+    let scale = match unit {
+        "ns" => 1e-9,
+        "us" | "µs" => 1e-6,
+        "ms" => 1e-3,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        _ => return None,
+    };
+    Duration::try_from_secs_f64(value * scale).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples = [5, 1, 3, 2, 4].map(Duration::from_micros);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(5, stats.samples);
+        assert_eq!(Duration::from_micros(1), stats.min);
+        assert_eq!(Duration::from_micros(3), stats.median);
+        assert_eq!(Duration::from_micros(3), stats.mean);
+        assert_eq!(1581, stats.stddev.as_nanos());
+
+        let mut samples = [4, 2].map(Duration::from_micros);
+        assert_eq!(
+            Duration::from_micros(3),
+            Stats::from_samples(&mut samples).median
+        );
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(Some(Duration::from_millis(500)), parse_duration("500ms"));
+        assert_eq!(Some(Duration::from_millis(1500)), parse_duration("1.5"));
+        assert_eq!(Some(Duration::from_secs(2)), parse_duration("2s"));
+        assert_eq!(Some(Duration::from_micros(20)), parse_duration("20us"));
+        assert_eq!(None, parse_duration("fast"));
+        assert_eq!(None, parse_duration("5 hours"));
+    }
+}
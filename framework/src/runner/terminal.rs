@@ -0,0 +1,20 @@
+use std::io::IsTerminal;
+
+/// Width of the terminal that stdout is printed to, or `None` when it isn't
+/// a terminal.
+pub fn width() -> Option<usize> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    #[cfg(unix)]
+    {
+        // `TIOCGWINSZ` only writes a `winsize` to the given pointer.
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+        if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0
+            && size.ws_col != 0
+        {
+            return Some(size.ws_col as usize);
+        }
+    }
+    std::env::var("COLUMNS").ok()?.parse().ok()
+}
@@ -0,0 +1,311 @@
+use crate::{
+    error::{Context, Error},
+    result::{Result, ResultExt},
+};
+use std::{fmt, io::Write, path::PathBuf, time::Duration};
+use thiserror::Error;
+
+/// The website's response to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently, try again after the duration.
+    Wait(Duration),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    Unrecognized,
+}
+
+impl Outcome {
+    /// Parses the page returned after submitting an answer.
+    pub fn parse(page: &str) -> Outcome {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::Wait(parse_wait_time(page).unwrap_or_default())
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unrecognized
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::Wait(_) => "wait",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unrecognized => "unrecognized",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        Some(match name {
+            "correct" => Outcome::Correct,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "incorrect" => Outcome::Incorrect,
+            "wait" => Outcome::Wait(Duration::ZERO),
+            "wrong_level" => Outcome::WrongLevel,
+            "unrecognized" => Outcome::Unrecognized,
+            _ => return None,
+        })
+    }
+
+    /// Whether the answer itself was judged, rather than the submission
+    /// being rejected.
+    pub fn is_judged(self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => f.write_str("correct"),
+            Outcome::TooHigh => f.write_str("incorrect, too high"),
+            Outcome::TooLow => f.write_str("incorrect, too low"),
+            Outcome::Incorrect => f.write_str("incorrect"),
+            Outcome::Wait(time) => {
+                let secs = time.as_secs();
+                write!(
+                    f,
+                    "submitted too recently, wait {}m {}s",
+                    secs / 60,
+                    secs % 60
+                )
+            }
+            Outcome::WrongLevel => f.write_str("already solved, or not unlocked yet"),
+            Outcome::Unrecognized => f.write_str("unrecognized response"),
+        }
+    }
+}
+
+/// Parses the `You have 4m 32s left to wait` message.
+fn parse_wait_time(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for part in page[start..end].split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        secs += value.parse::<u64>().ok()?
+            * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Reasons not to submit an answer, based on earlier submissions.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Refusal {
+    #[error("already solved, the answer was {0}")]
+    AlreadySolved(String),
+    #[error("this answer was already submitted, and was {0}")]
+    AlreadySubmitted(Outcome),
+    #[error("the answer is too high, {0} already was")]
+    TooHigh(String),
+    #[error("the answer is too low, {0} already was")]
+    TooLow(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Submission {
+    day: u32,
+    part: String,
+    outcome: Outcome,
+    answer: String,
+}
+
+/// Every answer submitted to the website, stored in `answers/submissions.txt`
+/// as `day part outcome answer` lines.
+#[derive(Debug)]
+pub struct Submissions {
+    path: PathBuf,
+}
+
+impl Default for Submissions {
+    fn default() -> Self {
+        Submissions {
+            path: PathBuf::from("./answers/submissions.txt"),
+        }
+    }
+}
+
+impl Submissions {
+    pub fn new() -> Submissions {
+        Default::default()
+    }
+
+    fn get(&self, day: u32, part: &str) -> Result<Vec<Submission>> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut submissions = Vec::new();
+        for (nr, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let submission = || -> Result<Submission> {
+                let mut fields = line.splitn(4, ' ');
+                let mut next = || {
+                    fields.next().ok_or(Error::InvalidInput(
+                        "malformed line in submissions file".into(),
+                    ))
+                };
+                Ok(Submission {
+                    day: next()?.parse().map_err(|_| {
+                        Error::InvalidInput("invalid day in submissions file".into())
+                    })?,
+                    part: next()?.to_owned(),
+                    outcome: Outcome::from_name(next()?).ok_or_else(|| {
+                        Error::InvalidInput("invalid outcome in submissions file".into())
+                    })?,
+                    answer: next()?.to_owned(),
+                })
+            };
+            let submission = submission().context(Context::line(nr + 1))?;
+            if submission.day == day && submission.part == part {
+                submissions.push(submission);
+            }
+        }
+        Ok(submissions)
+    }
+
+    /// Checks whether an answer is worth submitting.
+    pub fn check(&self, day: u32, part: &str, answer: &str) -> Result<Option<Refusal>> {
+        let submissions = self.get(day, part)?;
+        let judged = submissions.iter().filter(|s| s.outcome.is_judged());
+        let value = answer.parse::<i128>().ok();
+        let mut too_low: Option<(i128, &str)> = None;
+        let mut too_high: Option<(i128, &str)> = None;
+        for submission in judged {
+            if submission.outcome == Outcome::Correct {
+                return Ok(Some(Refusal::AlreadySolved(submission.answer.clone())));
+            }
+            if submission.answer == answer {
+                return Ok(Some(Refusal::AlreadySubmitted(submission.outcome)));
+            }
+            let Ok(bound) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+            let bound = (bound, submission.answer.as_str());
+            match submission.outcome {
+                Outcome::TooHigh if too_high.is_none_or(|(b, _)| bound.0 < b) => {
+                    too_high = Some(bound)
+                }
+                Outcome::TooLow if too_low.is_none_or(|(b, _)| bound.0 > b) => {
+                    too_low = Some(bound)
+                }
+                _ => {}
+            }
+        }
+        Ok(match (value, too_high, too_low) {
+            (Some(value), Some((bound, s)), _) if value >= bound => {
+                Some(Refusal::TooHigh(s.to_owned()))
+            }
+            (Some(value), _, Some((bound, s))) if value <= bound => {
+                Some(Refusal::TooLow(s.to_owned()))
+            }
+            _ => None,
+        })
+    }
+
+    pub fn record(&self, day: u32, part: &str, answer: &str, outcome: Outcome) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{day:0>2} {part} {} {answer}", outcome.name())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(
+            Outcome::Correct,
+            Outcome::parse("<p>That's the right answer!  You are one gold star closer")
+        );
+        assert_eq!(
+            Outcome::TooLow,
+            Outcome::parse("<p>That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            Outcome::Incorrect,
+            Outcome::parse("<p>That's not the right answer.  If you're stuck")
+        );
+        assert_eq!(
+            Outcome::Wait(Duration::from_secs(272)),
+            Outcome::parse("<p>You gave an answer too recently.  You have 4m 32s left to wait.")
+        );
+        assert_eq!(Outcome::Unrecognized, Outcome::parse("<html></html>"));
+    }
+
+    #[test]
+    fn refusals() {
+        let path = std::env::temp_dir().join(format!("aoc_submissions_{}", std::process::id()));
+        let submissions = Submissions { path: path.clone() };
+        submissions
+            .record(3, "pt1", "100", Outcome::TooHigh)
+            .unwrap();
+        submissions.record(3, "pt1", "10", Outcome::TooLow).unwrap();
+        submissions
+            .record(3, "pt1", "20", Outcome::Wait(Duration::ZERO))
+            .unwrap();
+        submissions
+            .record(3, "pt2", "abc", Outcome::Incorrect)
+            .unwrap();
+
+        let check = |part, answer| submissions.check(3, part, answer).unwrap();
+        assert_eq!(
+            Some(Refusal::TooHigh("100".to_owned())),
+            check("pt1", "150")
+        );
+        assert_eq!(Some(Refusal::TooLow("10".to_owned())), check("pt1", "5"));
+        assert_eq!(None, check("pt1", "20"));
+        assert_eq!(
+            Some(Refusal::AlreadySubmitted(Outcome::Incorrect)),
+            check("pt2", "abc")
+        );
+        assert_eq!(None, check("pt2", "def"));
+
+        submissions
+            .record(3, "pt2", "def", Outcome::Correct)
+            .unwrap();
+        assert_eq!(
+            Some(Refusal::AlreadySolved("def".to_owned())),
+            check("pt2", "ghi")
+        );
+        _ = std::fs::remove_file(path);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::iter::{DoubleEndedLendingIterator, LendingIterator};
 use std::{marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+use unicode_width::UnicodeWidthStr;
 
 pub fn init_array<T, E, const N: usize>(
     mut f: impl FnMut(usize) -> Result<T, E>,
@@ -34,6 +35,12 @@ pub fn init_array<T, E, const N: usize>(
     Ok(unsafe { MaybeUninit::array_assume_init(result) })
 }
 
+/// Number of columns that text takes up in a terminal. Wide characters, such
+/// as CJK and most emoji, take up two, and combining marks none.
+pub fn display_width(s: &str) -> usize {
+    s.width()
+}
+
 pub trait SliceExt<T> {
     fn trim_start(&self, element: &T) -> &Self
     where
@@ -205,3 +212,17 @@ macro_rules! __private__impl_eq_ord_by {
 pub macro impl_eq_ord_by($($token:tt)+) {
     $crate::__private__impl_eq_ord_by!($($token)+);
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn widths() {
+        assert_eq!(5, display_width("hello"));
+        assert_eq!(4, display_width("日本"));
+        assert_eq!(2, display_width("🎄"));
+        assert_eq!(1, display_width("e\u{301}"));
+        assert_eq!(1, display_width("✓"));
+    }
+}
@@ -14,7 +14,7 @@ fn pts<F: Fn(&[u8]) -> Option<u8>>(input: &[&[u8]], f: F) -> Result<u32> {
             Some((first * 10 + last) as u32)
         })
         .sum::<Option<u32>>()
-        .ok_or(Error::InvalidInput("no two digits in string"))
+        .ok_or(Error::InvalidInput("no two digits in string".into()))
 }
 
 fn pt1(input: &[&[u8]]) -> Result<u32> {
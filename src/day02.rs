@@ -63,7 +63,7 @@ fn parse(input: &[u8]) -> Result<Vec<Game>> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
@@ -96,7 +96,7 @@ fn parse(input: &[u8]) -> Result<Grid> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 467..114..
 ...*......
 ..35..633.
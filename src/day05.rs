@@ -23,7 +23,7 @@ fn pt1(almanac: &Almanac) -> u64 {
 }
 
 fn pt2(almanac: &Almanac) -> u64 {
-    let chunks = almanac.seeds.as_chunks().0.into_iter();
+    let chunks = almanac.seeds.as_chunks().0.iter();
     chunks
         .map(|&[start, count]| start..start + count)
         .map(|range| get_lowest_location_from_range(almanac, range))
@@ -117,7 +117,7 @@ fn parse(input: &[u8]) -> Result<Almanac> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 seeds: 79 14 55 13
 
 seed-to-soil map:
@@ -74,7 +74,7 @@ fn parse(input: &[u8]) -> Result<Input> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 Time:      7  15   30
 Distance:  9  40  200";
 
@@ -134,7 +134,7 @@ fn parse(input: &[u8]) -> Result<Input> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 32T3K 765
 T55J5 684
 KK677 28
@@ -148,7 +148,7 @@ fn get_cycle_info(input: &Input, cache: &mut HashSet<(Node, usize)>, initial: No
 
         if step != time_till_start {
             return Err(Error::InvalidInput(
-                "the input is not a simplified form of the problem",
+                "the input is not a simplified form of the problem".into(),
             ));
         }
         step = 0;
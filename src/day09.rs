@@ -87,7 +87,7 @@ fn parse(input: &[u8]) -> Result<Vec<Vec<i32>>> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45";
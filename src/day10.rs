@@ -15,7 +15,7 @@ fn get_starting_position(grid: &Grid) -> Result<Vec2> {
     grid.iter()
         .find(|&(_, &cell)| cell == START)
         .map(|(position, _)| position)
-        .ok_or(Error::InvalidInput("no starting point"))
+        .ok_or(Error::InvalidInput("no starting point".into()))
 }
 
 fn for_each_path_pos(grid: &Grid, mut f: impl FnMut(Vec2, u8)) -> Result<()> {
@@ -32,10 +32,10 @@ fn for_each_path_pos(grid: &Grid, mut f: impl FnMut(Vec2, u8)) -> Result<()> {
         })
         .collect_tuple()
         .ok_or(Error::InvalidInput(
-            "exactly two pipes need to be connected to starting point",
+            "exactly two pipes need to be connected to starting point".into(),
         ))?;
 
-    const OUT_OF_BOUNDS: Error = Error::InvalidInput("out of bounds");
+    const OUT_OF_BOUNDS: Error = Error::InvalidInput(std::borrow::Cow::Borrowed("out of bounds"));
     let mut current_dir = dir_a;
     let mut current_pos = starting_pos;
     f(current_pos, dir_a.raw() | dir_b.raw());
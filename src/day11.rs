@@ -75,7 +75,7 @@ fn parse(input: &[u8]) -> Result<BitGrid> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 ...#......
 .......#..
 #.........
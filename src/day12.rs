@@ -16,6 +16,7 @@ enum Cell {
 #[derive(Default)]
 struct State<'a> {
     cache: HashMap<(&'a [Cell], &'a [u8]), u64>,
+    checkpoint: Checkpoint,
 }
 
 impl<'a> State<'a> {
@@ -34,6 +35,7 @@ impl<'a> State<'a> {
             return cached;
         }
 
+        self.checkpoint.tick();
         let mut result = 0;
 
         // Empty case
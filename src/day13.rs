@@ -38,7 +38,7 @@ fn pts(grids: &[BitGrid], f: impl Fn(&BitGrid) -> Option<u64>) -> Result<u64> {
     (grids.iter())
         .map(|grid| f(grid).map(|n| n * 100).or_else(|| f(&grid.transpose())))
         .sum::<Option<u64>>()
-        .ok_or(Error::InvalidInput("no reflection exists"))
+        .ok_or(Error::InvalidInput("no reflection exists".into()))
 }
 
 fn pt1(grids: &[BitGrid]) -> Result<u64> {
@@ -57,7 +57,7 @@ fn parse(input: &[u8]) -> Result<Vec<BitGrid>> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 #.##..##.
 ..#.##.#.
 ##......#
@@ -167,7 +167,7 @@ fn parse(input: &[u8]) -> Result<Grid> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 O....#....
 O.OO#....#
 .....##...
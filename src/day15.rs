@@ -60,7 +60,7 @@ fn parse_instructions(input: &[u8]) -> Result<Vec<(Label<'_>, Option<NonZeroU8>)
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
     test_pt!(parse, pt1, EXAMPLE => 1320);
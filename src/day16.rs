@@ -102,7 +102,7 @@ fn parse(input: &[u8]) -> Result<Grid> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = br"
+    const EXAMPLE: &[u8] = br"
 .|...\....
 |.-.\.....
 .....|-...
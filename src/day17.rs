@@ -94,7 +94,7 @@ fn parse(input: &[u8]) -> Result<Grid> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 2413432311323
 3215453535623
 3255245654254
@@ -121,7 +121,7 @@ fn parse(input: &[u8]) -> Result<(Vec<Instruction>, Vec<Instruction>)> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
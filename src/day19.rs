@@ -34,7 +34,7 @@ fn pt1(input: &Input) -> Result<u64> {
         let mut ident = IN;
         'outer: while !matches!(ident, ACCEPT | REJECT) {
             let workflow = (input.workflows.iter().find(|w| w.ident == ident))
-                .ok_or(Error::InvalidInput("no workflow with name"))?;
+                .ok_or(Error::InvalidInput("no workflow with name".into()))?;
             for rule in &workflow.rules {
                 let n = part[rule.n as usize];
                 let matches = if rule.is_lt {
@@ -73,7 +73,7 @@ fn count_combinations(
         _ => (),
     }
     let workflow = (workflows.iter().find(|w| w.ident == ident))
-        .ok_or(Error::InvalidInput("no workflow with name"))?;
+        .ok_or(Error::InvalidInput("no workflow with name".into()))?;
 
     let mut combinations = 0;
     let mut remainder = ranges;
@@ -164,7 +164,7 @@ fn parse(input: &[u8]) -> Result<Input> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 px{a<2006:qkq,m>2090:A,rfg}
 pv{a>1716:R,A}
 lnx{m>1548:A,A}
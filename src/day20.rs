@@ -60,7 +60,10 @@ fn preprocess(modules: &[ParseModule]) -> Result<Processed> {
         })
         .collect_vec();
     let resolve = |name: &[u8]| {
-        (name_map.get(name).cloned()).ok_or(Error::InvalidInput("module name does not exist"))
+        (name_map.get(name).cloned()).ok_or_else(|| {
+            let name = String::from_utf8_lossy(name);
+            Error::InvalidInput(format!("module '{name}' does not exist").into())
+        })
     };
 
     for (idx, module) in modules.iter().enumerate() {
@@ -173,7 +176,7 @@ fn pt2(modules: &[ParseModule]) -> Result<u64> {
         broadcaster,
         rx,
     } = preprocess(modules)?;
-    let rx = rx.ok_or(Error::InvalidInput("missing rx"))?;
+    let rx = rx.ok_or(Error::InvalidInput("missing rx".into()))?;
     let mut pulses = PulseQueue::new();
 
     let conjunction_idx = (modules.iter())
@@ -181,7 +184,7 @@ fn pt2(modules: &[ParseModule]) -> Result<u64> {
             matches!(m.ty, ModuleType::Conjunction(_)) && m.outputs.len() == 1 && m.outputs[0] == rx
         })
         .exactly_one()
-        .map_err(|_| Error::InvalidInput("expected a single conjunction to wire into rx"))?;
+        .map_err(|_| Error::InvalidInput("expected a single conjunction to wire into rx".into()))?;
     let conjunction_range = match &modules[conjunction_idx].ty {
         ModuleType::Conjunction(range) => range.clone(),
         _ => unreachable!(),
@@ -252,13 +255,13 @@ fn parse(input: &[u8]) -> Result<Vec<ParseModule<'_>>> {
 }
 
 tests! {
-    const EXAMPLE1: &'static [u8] = b"\
+    const EXAMPLE1: &[u8] = b"\
 broadcaster -> a, b, c
 %a -> b
 %b -> c
 %c -> inv
 &inv -> a";
-    const EXAMPLE2: &'static [u8] = b"\
+    const EXAMPLE2: &[u8] = b"\
 broadcaster -> a
 %a -> inv, con
 &inv -> b
@@ -228,7 +228,7 @@ fn parse(input: &[u8]) -> Result<Input> {
 }
 
 tests! {
-    const PT1_EXAMPLE: &'static [u8] = b"\
+    const PT1_EXAMPLE: &[u8] = b"\
 ...........
 .....###.#.
 .###.##..#.
@@ -240,13 +240,13 @@ tests! {
 .##.#.####.
 .##..##.##.
 ...........";
-    const PT2_SIMPLE_EXAMPLE: &'static [u8] = b"\
+    const PT2_SIMPLE_EXAMPLE: &[u8] = b"\
 .....
 .....
 ..S..
 .....
 .....";
-    const PT2_COMPLEX_EXAMPLE: &'static [u8] = b"\
+    const PT2_COMPLEX_EXAMPLE: &[u8] = b"\
 ...........
 .####..#.#.
 .#.##..#.#.
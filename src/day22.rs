@@ -33,10 +33,10 @@ impl BrickStructure {
             min_xy = min_xy.min_comp(brick.0.xy());
             max_xy = max_xy.max_comp(brick.1.xy());
         }
-        bricks.sort_unstable_by(|a, b| a.0.z.cmp(&b.0.z));
+        bricks.sort_unstable_by_key(|a| a.0.z);
 
         if min_xy != Vec2::zero() {
-            return Err(Error::InvalidInput("expected bricks at zero"));
+            return Err(Error::InvalidInput("expected bricks at zero".into()));
         }
 
         let heightmap = VecGrid::new(max_xy + 1, |_| Cell::default());
@@ -152,7 +152,7 @@ fn parse(input: &[u8]) -> Result<Vec<(Vec3, Vec3)>> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -116,7 +116,15 @@ fn build_graph(grid: &Grid) -> Vec<Node> {
 fn pt1(grid: &Grid) -> u32 {
     let nodes = build_graph(grid);
     assert!(nodes.len() < 64);
-    fn dfs(index: u32, visited: u64, length: u32, nodes: &[Node], longest_path: &mut u32) {
+    fn dfs(
+        index: u32,
+        visited: u64,
+        length: u32,
+        nodes: &[Node],
+        longest_path: &mut u32,
+        checkpoint: &mut Checkpoint,
+    ) {
+        checkpoint.tick();
         if index as usize == nodes.len() - 1 {
             *longest_path = length.max(*longest_path);
             return;
@@ -126,12 +134,19 @@ fn pt1(grid: &Grid) -> u32 {
             if visited & (1 << next_idx) != 0 {
                 continue;
             }
-            dfs(next_idx, visited, length + next_dist, nodes, longest_path);
+            dfs(
+                next_idx,
+                visited,
+                length + next_dist,
+                nodes,
+                longest_path,
+                checkpoint,
+            );
         }
     }
 
     let mut longest_path = 0;
-    dfs(0, 0, 0, &nodes, &mut longest_path);
+    dfs(0, 0, 0, &nodes, &mut longest_path, &mut Checkpoint::new());
     longest_path
 }
 
@@ -162,7 +177,7 @@ fn parse(input: &[u8]) -> Result<Grid> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 #.#####################
 #.......#########...###
 #######.#########.#.###
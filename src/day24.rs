@@ -111,7 +111,7 @@ fn pt2(stones: &[Hailstone]) -> Result<i64> {
     let delta_time = time_b - time_a;
     let delta_pos = rock_pos_at_b - rock_pos_at_a;
     if delta_pos % delta_time != Vec3::zero() {
-        return Err(Error::InvalidInput("rock velocity is not integer"));
+        return Err(Error::InvalidInput("rock velocity is not integer".into()));
     }
     let rock_velocity = delta_pos / delta_time;
     let rock_position = rock_pos_at_a - rock_velocity * time_a;
@@ -126,14 +126,16 @@ fn intersection_time(reference_line: &Hailstone, stone: &Hailstone) -> Result<i6
 
     let denominator = stone.vel.to_i128().dot(plane_normal);
     if denominator == 0 {
-        return Err(Error::InvalidInput("line and plane are parallel"));
+        return Err(Error::InvalidInput("line and plane are parallel".into()));
     }
     let numerator = -stone.pos.to_i128().dot(plane_normal);
     if numerator % denominator != 0 {
-        return Err(Error::InvalidInput("intersection time was not an integer"));
+        return Err(Error::InvalidInput(
+            "intersection time was not an integer".into(),
+        ));
     }
     (i64::try_from(numerator / denominator).ok())
-        .ok_or(Error::InvalidInput("intersection time overflow"))
+        .ok_or(Error::InvalidInput("intersection time overflow".into()))
 }
 
 fn parse(input: &[u8]) -> Result<Vec<Hailstone>> {
@@ -158,7 +160,7 @@ fn parse(input: &[u8]) -> Result<Vec<Hailstone>> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 19, 13, 30 @ -2, 1, -2
 18, 19, 22 @ -1, -1, -2
 20, 25, 34 @ -2, -2, -4
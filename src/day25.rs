@@ -401,7 +401,7 @@ fn parse(input: &[u8]) -> Result<Vec<Wiring>> {
 }
 
 tests! {
-    const EXAMPLE: &'static [u8] = b"\
+    const EXAMPLE: &[u8] = b"\
 jqt: rhn xhk nvd
 rsh: frs pzl lsr
 xhk: hfx
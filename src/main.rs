@@ -3,7 +3,7 @@
 #![feature(map_try_insert)]
 #![feature(slice_partition_dedup)]
 
-#![feature(custom_test_frameworks)]
+#![cfg_attr(feature = "criterion", feature(custom_test_frameworks))]
 #![cfg_attr(feature = "criterion", test_runner(criterion::runner))]
 
 #![allow(clippy::zero_prefixed_literal)]